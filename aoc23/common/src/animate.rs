@@ -0,0 +1,355 @@
+//! Recording step-by-step simulations as animations: an animated GIF, or a PNG image per frame.
+//!
+//! A day's `animate` function runs its simulation, offering an [`Animation`] a [`Canvas`] of
+//! each step. The animation keeps one step in every few as a frame, cropped if asked. A crop
+//! that leaves nothing of the first frame is an error, from the first step.
+
+use crate::render::{Canvas, PIXEL_SCALE};
+use anyhow::{anyhow, bail, Context, Result};
+use clap::ValueEnum;
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::BufWriter;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Cells to keep of each frame, as in [`Canvas::crop`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Crop {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl FromStr for Crop {
+    type Err = anyhow::Error;
+
+    /// `X,Y,WIDTH,HEIGHT`, with neither the width nor the height zero.
+    fn from_str(s: &str) -> Result<Crop> {
+        let numbers = s
+            .split(',')
+            .map(|n| n.trim().parse())
+            .collect::<Result<Vec<usize>, _>>()
+            .context("expected X,Y,WIDTH,HEIGHT")?;
+        match numbers[..] {
+            [_, _, width, height] if width == 0 || height == 0 => {
+                Err(anyhow!("the crop is empty: {}x{} cells", width, height))
+            }
+            [x, y, width, height] => Ok(Crop {
+                x,
+                y,
+                width,
+                height,
+            }),
+            _ => Err(anyhow!("expected X,Y,WIDTH,HEIGHT")),
+        }
+    }
+}
+
+/// The frames recorded from a simulation, each as the RGB colour of every cell.
+#[derive(Debug, Clone)]
+pub struct Animation {
+    skip: NonZeroUsize,
+    crop: Option<Crop>,
+    steps: usize,
+    last_kept: bool,
+    size: Option<(usize, usize)>,
+    frames: Vec<Vec<u8>>,
+}
+
+impl Animation {
+    /// An animation keeping the first step and every `skip`th after it.
+    pub fn new(skip: NonZeroUsize, crop: Option<Crop>) -> Animation {
+        Animation {
+            skip,
+            crop,
+            steps: 0,
+            last_kept: false,
+            size: None,
+            frames: Vec::new(),
+        }
+    }
+
+    /// Record a step of the simulation. `canvas` is only drawn for the steps that are kept.
+    /// It's an error if the crop leaves nothing of it.
+    ///
+    /// # Panics
+    ///
+    /// If a kept canvas isn't the same size as the first.
+    pub fn step(&mut self, canvas: impl FnOnce() -> Canvas) -> Result<()> {
+        self.last_kept = self.steps % self.skip == 0;
+        self.steps += 1;
+        if self.last_kept {
+            self.keep(canvas())?;
+        }
+        Ok(())
+    }
+
+    /// Record the end of the simulation: the state after its last step, which is kept as a
+    /// frame if that step was skipped.
+    pub fn finish(&mut self, canvas: impl FnOnce() -> Canvas) -> Result<()> {
+        if !self.last_kept {
+            self.keep(canvas())?;
+            self.last_kept = true;
+        }
+        Ok(())
+    }
+
+    pub fn frame_count(&self) -> usize {
+        self.frames.len()
+    }
+
+    fn keep(&mut self, canvas: Canvas) -> Result<()> {
+        let canvas = match self.crop {
+            Some(Crop {
+                x,
+                y,
+                width,
+                height,
+            }) => {
+                let cropped = canvas.crop(x, y, width, height);
+                if cropped.width() == 0 || cropped.height() == 0 {
+                    bail!(
+                        "the crop from {},{} is outside the {}x{} grid",
+                        x,
+                        y,
+                        canvas.width(),
+                        canvas.height()
+                    );
+                }
+                cropped
+            }
+            None => canvas,
+        };
+        let size = (canvas.width(), canvas.height());
+        let first = *self.size.get_or_insert(size);
+        assert_eq!(first, size, "every frame must be the same size");
+        self.frames.push(canvas.pixels(1));
+        Ok(())
+    }
+
+    /// Write the frames to `path` as a looping GIF, with `delay` hundredths of a second
+    /// between them.
+    pub fn write_gif(&self, path: &Path, delay: u16) -> Result<()> {
+        let (width, height) = self.size.ok_or_else(|| anyhow!("no frames to write"))?;
+        let too_big = || anyhow!("{}x{} cells is too big for a GIF; crop it", width, height);
+        let gif_width = u16::try_from(width * PIXEL_SCALE).map_err(|_| too_big())?;
+        let gif_height = u16::try_from(height * PIXEL_SCALE).map_err(|_| too_big())?;
+
+        // Our grids use few colours, so they usually fit one palette shared by every frame.
+        // Otherwise each frame gets its own, quantized from its pixels.
+        let mut palette = HashMap::new();
+        for pixel in self.frames.iter().flat_map(|frame| frame.chunks(3)) {
+            let next = palette.len();
+            palette.entry(pixel).or_insert(next);
+        }
+        let palette = (palette.len() <= 256).then_some(palette);
+        let mut global_palette = vec![0; palette.as_ref().map_or(0, |p| p.len() * 3)];
+        for (pixel, &index) in palette.iter().flatten() {
+            global_palette[index * 3..][..3].copy_from_slice(pixel);
+        }
+
+        let file = File::create(path).with_context(|| format!("creating {}", path.display()))?;
+        let mut encoder =
+            gif::Encoder::new(BufWriter::new(file), gif_width, gif_height, &global_palette)?;
+        encoder.set_repeat(gif::Repeat::Infinite)?;
+        for frame in &self.frames {
+            let mut frame = match &palette {
+                Some(palette) => {
+                    let indices: Vec<u8> =
+                        frame.chunks(3).map(|pixel| palette[pixel] as u8).collect();
+                    gif::Frame::from_indexed_pixels(
+                        gif_width,
+                        gif_height,
+                        upscale(&indices, width, 1),
+                        None,
+                    )
+                }
+                None => {
+                    gif::Frame::from_rgb_speed(gif_width, gif_height, &upscale(frame, width, 3), 10)
+                }
+            };
+            frame.delay = delay;
+            encoder
+                .write_frame(&frame)
+                .with_context(|| format!("writing {}", path.display()))?;
+        }
+        Ok(())
+    }
+
+    /// Write each frame to `dir` as `frame00000.png`, `frame00001.png` and so on, creating
+    /// the directory if it doesn't exist.
+    pub fn write_pngs(&self, dir: &Path) -> Result<()> {
+        let (width, height) = self.size.ok_or_else(|| anyhow!("no frames to write"))?;
+        fs::create_dir_all(dir).with_context(|| format!("creating {}", dir.display()))?;
+        for (i, frame) in self.frames.iter().enumerate() {
+            let path = dir.join(format!("frame{:05}.png", i));
+            let context = || format!("writing {}", path.display());
+            let file = File::create(&path).with_context(context)?;
+            let mut encoder = png::Encoder::new(
+                BufWriter::new(file),
+                (width * PIXEL_SCALE) as u32,
+                (height * PIXEL_SCALE) as u32,
+            );
+            encoder.set_color(png::ColorType::Rgb);
+            encoder.set_depth(png::BitDepth::Eight);
+            encoder
+                .write_header()
+                .and_then(|mut writer| writer.write_image_data(&upscale(frame, width, 3)))
+                .with_context(context)?;
+        }
+        Ok(())
+    }
+}
+
+/// Scale up an image of `width` pixels of `bytes` bytes each by [`PIXEL_SCALE`].
+fn upscale(pixels: &[u8], width: usize, bytes: usize) -> Vec<u8> {
+    let mut out = Vec::with_capacity(pixels.len() * PIXEL_SCALE * PIXEL_SCALE);
+    for row in pixels.chunks(width * bytes) {
+        let scaled: Vec<u8> = row
+            .chunks(bytes)
+            .flat_map(|pixel| pixel.repeat(PIXEL_SCALE))
+            .collect();
+        for _ in 0..PIXEL_SCALE {
+            out.extend_from_slice(&scaled);
+        }
+    }
+    out
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum AnimationFormat {
+    Gif,
+    Png,
+}
+
+/// The command-line arguments for animating a day's simulation, to `#[command(flatten)]` into
+/// its binary's arguments.
+#[derive(Debug, Clone, clap::Args)]
+#[command(about = None, long_about = None)]
+pub struct AnimateArgs {
+    /// Record the simulation: gif writes an animation, png an image for each frame
+    #[arg(long, value_enum)]
+    pub animate: Option<AnimationFormat>,
+
+    /// Where to write the animation [default: dayN.gif, or the directory dayN-frames]
+    #[arg(long, requires = "animate")]
+    pub animate_output: Option<PathBuf>,
+
+    /// Keep one step in every N as a frame
+    #[arg(long, value_name = "N", default_value = "1", requires = "animate")]
+    pub frame_skip: NonZeroUsize,
+
+    /// Hundredths of a second between the frames of a GIF
+    #[arg(long, default_value_t = 5, requires = "animate")]
+    pub frame_delay: u16,
+
+    /// Only draw the cells from X,Y across and down, given as X,Y,WIDTH,HEIGHT
+    #[arg(long, requires = "animate")]
+    pub crop: Option<Crop>,
+}
+
+impl AnimateArgs {
+    /// Run `simulate` and write its animation in the chosen format, if one was chosen, saying
+    /// where on stderr, to keep stdout for the answers.
+    pub fn write(
+        &self,
+        day: u8,
+        simulate: impl FnOnce(&mut Animation) -> Result<()>,
+    ) -> Result<()> {
+        let Some(format) = self.animate else {
+            return Ok(());
+        };
+        let mut animation = Animation::new(self.frame_skip, self.crop);
+        simulate(&mut animation).context("animating")?;
+        if animation.frame_count() == 0 {
+            bail!("the simulation had no steps to animate");
+        }
+        let path = self.animate_output.clone().unwrap_or_else(|| match format {
+            AnimationFormat::Gif => PathBuf::from(format!("day{}.gif", day)),
+            AnimationFormat::Png => PathBuf::from(format!("day{}-frames", day)),
+        });
+        match format {
+            AnimationFormat::Gif => animation.write_gif(&path, self.frame_delay)?,
+            AnimationFormat::Png => animation.write_pngs(&path)?,
+        }
+        eprintln!(
+            "Wrote {} frames to {}",
+            animation.frame_count(),
+            path.display()
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render::{Color, Style};
+    use crate::Point;
+    use grid::Grid;
+    use std::env;
+
+    /// A 3 x 2 canvas with the `n`th cell highlighted.
+    fn frame(n: usize) -> Canvas {
+        let mut canvas = Canvas::new(&Grid::init(2, 3, ()), |_| Style::new('.', Color::BLACK));
+        canvas.highlight([Point::new(n % 3, n / 3)], Color::RED);
+        canvas
+    }
+
+    #[test]
+    fn skips_and_crops() {
+        let crop = "1,0,5,1".parse().unwrap();
+        let mut animation = Animation::new(NonZeroUsize::new(2).unwrap(), Some(crop));
+        for n in 0..6 {
+            animation.step(|| frame(n)).unwrap();
+        }
+        // Step 5 was skipped, but the final state is always kept.
+        animation.finish(|| frame(5)).unwrap();
+        assert_eq!(animation.size, Some((2, 1)));
+        assert_eq!(
+            animation.frames,
+            [
+                vec![0, 0, 0, 0, 0, 0],
+                vec![0, 0, 0, 220, 50, 47],
+                vec![0, 0, 0, 0, 0, 0],
+                vec![0, 0, 0, 0, 0, 0],
+            ]
+        );
+        assert!("1,2,3".parse::<Crop>().is_err());
+        assert!("1,2,0,3".parse::<Crop>().is_err());
+    }
+
+    #[test]
+    fn rejects_crops_outside_the_grid() {
+        let crop = "3,0,5,5".parse().unwrap();
+        let mut animation = Animation::new(NonZeroUsize::MIN, Some(crop));
+        let err = animation.step(|| frame(0)).unwrap_err();
+        assert_eq!(err.to_string(), "the crop from 3,0 is outside the 3x2 grid");
+        assert_eq!(animation.frame_count(), 0);
+    }
+
+    #[test]
+    fn writes_gif_and_pngs() {
+        let mut animation = Animation::new(NonZeroUsize::MIN, None);
+        for n in 0..3 {
+            animation.step(|| frame(n)).unwrap();
+        }
+        animation.finish(|| frame(2)).unwrap();
+        assert_eq!(animation.frame_count(), 3);
+
+        let dir = env::temp_dir().join(format!("aoc-animate-{}", std::process::id()));
+        let gif = dir.join("test.gif");
+        animation.write_pngs(&dir).unwrap();
+        animation.write_gif(&gif, 5).unwrap();
+        let gif = fs::read(gif).unwrap();
+        let png = fs::read(dir.join("frame00002.png")).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(gif.starts_with(b"GIF89a"));
+        assert_eq!(gif[6..10], [12, 0, 8, 0]);
+        assert!(png.starts_with(b"\x89PNG"));
+    }
+}
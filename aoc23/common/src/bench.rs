@@ -0,0 +1,46 @@
+//! Criterion benchmarks shared by every day: parse, part one and part two, each timed
+//! separately on the day's example and on its real input, if there is one.
+
+use crate::input;
+use crate::solution::{Answer, Solution};
+use criterion::{black_box, Criterion};
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+
+/// The inputs to benchmark `day` on, by name: `example`, and `input` if `dayN.txt` exists in
+/// `$AOC_INPUT_DIR` or the workspace's `inputs/`.
+pub fn inputs(day: u8) -> Vec<(&'static str, String)> {
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let input_dir =
+        env::var_os(input::DIR_VAR).map_or_else(|| workspace.join("inputs"), PathBuf::from);
+    [
+        (
+            "example",
+            workspace.join(format!("examples/day{}.txt", day)),
+        ),
+        ("input", input_dir.join(format!("day{}.txt", day))),
+    ]
+    .into_iter()
+    .filter_map(|(name, path): (_, PathBuf)| {
+        let input = fs::read_to_string(path).ok()?;
+        Some((name, input.replace("\r\n", "\n")))
+    })
+    .collect()
+}
+
+/// Benchmark parsing and both parts of `S` on each of [`inputs`]. Unsolved parts are skipped.
+pub fn solution<S: Solution>(c: &mut Criterion, day: u8) {
+    for (name, input) in inputs(day) {
+        let mut group = c.benchmark_group(format!("day{}/{}", day, name));
+        group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&input))));
+
+        let parsed = S::parse(&input).expect("benchmark input should parse");
+        if S::part_one(&parsed).answer().is_some() {
+            group.bench_function("part one", |b| b.iter(|| S::part_one(black_box(&parsed))));
+        }
+        if S::part_two(&parsed).answer().is_some() {
+            group.bench_function("part two", |b| b.iter(|| S::part_two(black_box(&parsed))));
+        }
+        group.finish();
+    }
+}
@@ -0,0 +1,103 @@
+//! Finding where a sequence of states starts repeating, to skip ahead through long simulations.
+//!
+//! States are kept in a hashed history, so each step costs one lookup however long the
+//! sequence runs before it repeats.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::iter;
+
+/// The shape of a sequence that repeats: some states, then a loop of others.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// The number of states before the loop.
+    pub prefix: usize,
+    /// The number of states in the loop.
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The index of the first state equal to state `n`, which is below `prefix + period`.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.prefix {
+            n
+        } else {
+            self.prefix + (n - self.prefix) % self.period
+        }
+    }
+}
+
+/// The states of a sequence up to the end of its first loop.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct History<S> {
+    pub cycle: Cycle,
+    /// Every distinct state, in order: the prefix and then one pass of the loop.
+    pub states: Vec<S>,
+}
+
+impl<S> History<S> {
+    /// State `n` of the sequence, counting the first as 0, however far past the history.
+    pub fn nth(&self, n: usize) -> &S {
+        &self.states[self.cycle.reduce(n)]
+    }
+}
+
+/// Take states from `states` until one repeats. Returns `None` if they run out first.
+pub fn detect<S: Clone + Hash + Eq>(states: impl IntoIterator<Item = S>) -> Option<History<S>> {
+    let mut seen = HashMap::new();
+    let mut history = Vec::new();
+    for state in states {
+        if let Some(&prefix) = seen.get(&state) {
+            let period = history.len() - prefix;
+            return Some(History {
+                cycle: Cycle { prefix, period },
+                states: history,
+            });
+        }
+        seen.insert(state.clone(), history.len());
+        history.push(state);
+    }
+    None
+}
+
+/// Find the cycle in `start` and the states after it, each made from the one before by `step`.
+/// The states must be finite in number, or this never returns.
+pub fn iterate<S: Clone + Hash + Eq>(start: S, mut step: impl FnMut(&S) -> S) -> History<S> {
+    detect(iter::successors(Some(start), |state| Some(step(state))))
+        .expect("the sequence is infinite")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prefix_and_period() {
+        // 0, 1, 2, 3, 4, 5, 6, 2, 3, ...: two states, then a loop of five.
+        let history = iterate(0, |&n| if n == 6 { 2 } else { n + 1 });
+        assert_eq!(
+            history.cycle,
+            Cycle {
+                prefix: 2,
+                period: 5
+            }
+        );
+        assert_eq!(history.states, [0, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(*history.nth(1), 1);
+        assert_eq!(*history.nth(7), 2);
+        assert_eq!(*history.nth(1_000_000_000), 2 + (1_000_000_000 - 2) % 5);
+    }
+
+    #[test]
+    fn detect_needs_a_repeat() {
+        assert_eq!(detect([1, 2, 3]), None);
+        let history = detect([1, 1]).unwrap();
+        assert_eq!(
+            history.cycle,
+            Cycle {
+                prefix: 0,
+                period: 1
+            }
+        );
+    }
+}
@@ -0,0 +1,167 @@
+//! Random puzzle inputs, for fuzzing the parsers and solvers and for benchmarking at scale.
+//!
+//! Each day has a `generate` module whose `generate` function is a [`Generator`]: it writes a
+//! valid puzzle input in the text format the day parses, growing with a `size` whose meaning is
+//! documented there.
+
+use crate::direction::Direction;
+use crate::part::Part;
+use crate::point::Point;
+use crate::solution::{Answer, Solution};
+use crate::stream::{self, Streaming};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::HashSet;
+use std::panic::{self, AssertUnwindSafe};
+
+/// A day's input generator.
+pub type Generator = fn(&mut StdRng, usize) -> String;
+
+/// Run `generator` with a random number generator seeded from `seed`, so the same seed and size
+/// always give the same input.
+pub fn seeded(generator: Generator, seed: u64, size: usize) -> String {
+    generator(&mut StdRng::seed_from_u64(seed), size)
+}
+
+/// Parse and solve both parts of generated inputs for a range of seeds, panicking with the seed
+/// and the input if any of them fails. For the generator tests of each day.
+pub fn exercise<S: Solution>(generator: Generator, size: usize, seeds: u64) {
+    for seed in 0..seeds {
+        let input = seeded(generator, seed, size);
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            let parsed = S::parse(&input).unwrap();
+            (S::part_one(&parsed).answer(), S::part_two(&parsed).answer())
+        }));
+        if result.is_err() {
+            panic!("seed {} size {} failed on:\n{}", seed, size, input);
+        }
+    }
+}
+
+/// Check that streaming generated inputs for a range of seeds gives the same answers as solving
+/// them whole, panicking with the seed and the input if not. For the days that can stream.
+pub fn exercise_streaming<S: Streaming>(generator: Generator, size: usize, seeds: u64) {
+    for seed in 0..seeds {
+        let input = seeded(generator, seed, size);
+        let whole = crate::solve::<S>(&input, &Part::ALL, &[]).unwrap();
+        let streamed = stream::solve::<S>(&mut input.as_bytes(), &Part::ALL).unwrap();
+        for part in Part::ALL {
+            assert_eq!(
+                streamed.answer(part),
+                whole.answer(part),
+                "seed {} size {} part {} streamed differently on:\n{}",
+                seed,
+                size,
+                part,
+                input
+            );
+        }
+    }
+}
+
+/// Grow a random tree over at most `cells` cells of a `width` x `height` grid, starting from
+/// `root`. Returns the edges as (parent, child) pairs, each parent already in the tree.
+pub fn random_tree<R: Rng>(
+    rng: &mut R,
+    width: usize,
+    height: usize,
+    root: Point<usize>,
+    cells: usize,
+) -> Vec<(Point<usize>, Point<usize>)> {
+    let mut in_tree = HashSet::from([root]);
+    let mut frontier: Vec<_> = root.neighbors(width, height).map(|n| (root, n)).collect();
+    let mut edges = Vec::new();
+    while in_tree.len() < cells && !frontier.is_empty() {
+        let (parent, child) = frontier.swap_remove(rng.gen_range(0..frontier.len()));
+        if !in_tree.insert(child) {
+            continue;
+        }
+        edges.push((parent, child));
+        frontier.extend(
+            child
+                .neighbors(width, height)
+                .filter(|n| !in_tree.contains(n))
+                .map(|n| (child, n)),
+        );
+    }
+    edges
+}
+
+/// A random simple loop through a `2 * width` x `2 * height` grid, as the cells it passes
+/// through in order. Consecutive cells, and the last and first, are orthogonal neighbours.
+///
+/// The loop goes around a random tree of 2 x 2 blocks, so it covers between half and all of the
+/// grid and has no shortcuts between neighbouring cells that aren't adjacent along it.
+pub fn random_loop<R: Rng>(rng: &mut R, width: usize, height: usize) -> Vec<Point<usize>> {
+    let blocks = width * height;
+    let root = Point::new(rng.gen_range(0..width), rng.gen_range(0..height));
+    let size = rng.gen_range(blocks.div_ceil(2)..=blocks);
+    let edges: HashSet<_> = random_tree(rng, width, height, root, size)
+        .into_iter()
+        .flat_map(|(a, b)| [(a, b), (b, a)])
+        .collect();
+    let joined = |block: Point<usize>, dir: Direction| {
+        block
+            .step(dir, width, height)
+            .is_some_and(|next| edges.contains(&(block, next)))
+    };
+
+    // Each cell of a block faces out of it on two sides. Across a side joined to the next block,
+    // the loop steps into that block; otherwise it turns along the block's edge.
+    let next = |cell: Point<usize>, from: Point<usize>| {
+        let block = Point::new(cell.x / 2, cell.y / 2);
+        let horizontal = if cell.x.is_multiple_of(2) {
+            Direction::Left
+        } else {
+            Direction::Right
+        };
+        let vertical = if cell.y.is_multiple_of(2) {
+            Direction::Up
+        } else {
+            Direction::Down
+        };
+        let [a, b] = [(horizontal, vertical), (vertical, horizontal)].map(|(side, along)| {
+            let dir = if joined(block, side) {
+                side
+            } else {
+                along.opposite()
+            };
+            cell.step(dir, 2 * width, 2 * height)
+                .expect("the loop stays inside the grid")
+        });
+        if a == from {
+            b
+        } else {
+            a
+        }
+    };
+
+    let start = Point::new(2 * root.x, 2 * root.y);
+    let mut cells = vec![start];
+    let mut previous = start;
+    let mut current = next(start, start);
+    while current != start {
+        cells.push(current);
+        (previous, current) = (current, next(current, previous));
+    }
+    cells
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn loops_are_simple_and_closed() {
+        for seed in 0..50 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let (width, height) = (rng.gen_range(1..8), rng.gen_range(1..8));
+            let cells = random_loop(&mut rng, width, height);
+            assert!(cells.len() >= 4 && cells.len().is_multiple_of(2));
+            assert_eq!(cells.iter().collect::<HashSet<_>>().len(), cells.len());
+            for (a, b) in cells.iter().zip(cells.iter().cycle().skip(1)) {
+                assert_eq!(a.x.abs_diff(b.x) + a.y.abs_diff(b.y), 1, "seed {}", seed);
+            }
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use crate::parse::ParseError;
+use crate::point::Point;
+use std::convert::Infallible;
+use std::fmt::Display;
+
+pub use grid::Grid;
+
+/// Parse a rectangular block of characters into a grid, mapping each character to a cell.
+///
+/// Leading and trailing whitespace around the block and around each line is ignored, so both
+/// `\n` and `\r\n` line endings are accepted. It's an error if the rows aren't all the same
+/// length.
+pub fn parse_grid<T>(input: &str, mut cell: impl FnMut(char) -> T) -> Result<Grid<T>, ParseError> {
+    try_parse_grid(input, |c| Ok::<T, Infallible>(cell(c)))
+}
+
+/// Like [`parse_grid`], but the character mapping may fail. Errors, including ragged rows, are
+/// reported at the line and column they occur.
+pub fn try_parse_grid<T, E: Display>(
+    input: &str,
+    mut cell: impl FnMut(char) -> Result<T, E>,
+) -> Result<Grid<T>, ParseError> {
+    let mut cols = None;
+    let mut cells = Vec::new();
+    let lines = input
+        .trim_end()
+        .lines()
+        .enumerate()
+        .skip_while(|(_, line)| line.trim().is_empty());
+    for (row, line) in lines {
+        let indent = line.chars().take_while(|c| c.is_whitespace()).count();
+        let line = line.trim();
+        let count = line.chars().count();
+        let width = *cols.get_or_insert(count);
+        if count != width {
+            return Err(ParseError::new(
+                row + 1,
+                indent + 1,
+                format!("expected a row of {} cells, found {}", width, count),
+            ));
+        }
+        for (col, c) in line.chars().enumerate() {
+            let value =
+                cell(c).map_err(|e| ParseError::new(row + 1, indent + col + 1, e.to_string()))?;
+            cells.push(value);
+        }
+    }
+    Ok(Grid::from_vec(cells, cols.unwrap_or(0)))
+}
+
+/// The location of the first cell, in row-major order, that matches `pred`.
+pub fn position<T>(grid: &Grid<T>, mut pred: impl FnMut(&T) -> bool) -> Option<Point<usize>> {
+    grid.indexed_iter()
+        .find(|(_, cell)| pred(cell))
+        .map(|((row, col), _)| Point::new(col, row))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::BadChar;
+
+    #[test]
+    fn parse() {
+        let grid = parse_grid("#.\r\n.#\r\n..\r\n", |c| c == '#').unwrap();
+        assert_eq!(grid.rows(), 3);
+        assert_eq!(grid.cols(), 2);
+        assert_eq!(grid.get(0, 0), Some(&true));
+        assert_eq!(grid.get(1, 0), Some(&false));
+        assert_eq!(grid.get(1, 1), Some(&true));
+        assert_eq!(position(&grid, |&c| c), Some(Point::new(0, 0)));
+
+        let ragged = parse_grid("#.\n.", |c| c == '#');
+        assert_eq!(
+            ragged.unwrap_err().to_string(),
+            "line 2, column 1: expected a row of 2 cells, found 1"
+        );
+    }
+
+    #[test]
+    fn parse_fallible() {
+        let digit = |c: char| {
+            c.to_digit(10).ok_or(BadChar {
+                found: c,
+                expected: "0-9",
+            })
+        };
+        let grid = try_parse_grid("\n12\n3x\n", digit);
+        assert_eq!(
+            grid.unwrap_err().to_string(),
+            "line 3, column 2: expected one of \"0-9\", found 'x'"
+        );
+        let grid = try_parse_grid("12\n345", digit);
+        assert_eq!(
+            grid.unwrap_err().to_string(),
+            "line 2, column 1: expected a row of 2 cells, found 3"
+        );
+    }
+}
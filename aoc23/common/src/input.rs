@@ -0,0 +1,125 @@
+//! Finding and reading puzzle inputs.
+//!
+//! An input is read from `--input PATH`, or stdin for `--input -`. Without one, it's
+//! `dayN.txt` in the directory named by `AOC_INPUT_DIR`, or in `inputs/` if that isn't set.
+//!
+//! Besides [`read`]ing it into a string, an input can be [`map`]ped into memory, which doesn't
+//! copy a large file, or [`open`]ed to be read a line at a time by a day that can
+//! [stream](crate::stream) it.
+
+use anyhow::{Context, Result};
+use clap::Parser;
+use memmap2::Mmap;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read};
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::{env, fs, io};
+
+/// The environment variable naming the directory that holds `dayN.txt` inputs.
+pub const DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// The command-line arguments for choosing an input, for binaries that take no others, or to
+/// `#[command(flatten)]` into those that do.
+#[derive(Debug, Clone, Parser)]
+#[command(about = None, long_about = None)]
+pub struct InputArgs {
+    /// Puzzle input file, or - for stdin [default: $AOC_INPUT_DIR/dayN.txt, or inputs/dayN.txt]
+    #[arg(short, long)]
+    pub input: Option<String>,
+}
+
+impl InputArgs {
+    /// Read the chosen input, or `day`'s default one.
+    pub fn read(&self, day: u8) -> Result<String> {
+        match &self.input {
+            Some(path) => read(path),
+            None => read(default_path(day)),
+        }
+    }
+
+    /// Map the chosen input, or `day`'s default one, into memory.
+    pub fn map(&self, day: u8) -> Result<Text> {
+        match &self.input {
+            Some(path) => map(path),
+            None => map(default_path(day)),
+        }
+    }
+
+    /// Open the chosen input, or `day`'s default one, for reading.
+    pub fn open(&self, day: u8) -> Result<Box<dyn BufRead>> {
+        match &self.input {
+            Some(path) => open(path),
+            None => open(default_path(day)),
+        }
+    }
+}
+
+/// The directory holding every day's input: `$AOC_INPUT_DIR`, or `inputs`.
+pub fn dir() -> PathBuf {
+    env::var_os(DIR_VAR).map_or_else(|| PathBuf::from("inputs"), PathBuf::from)
+}
+
+/// Where `day`'s input is, if no other path is given.
+pub fn default_path(day: u8) -> PathBuf {
+    dir().join(format!("day{}.txt", day))
+}
+
+/// Read the input at `path`, or stdin if it's `-`.
+pub fn read(path: impl AsRef<Path>) -> Result<String> {
+    let path = path.as_ref();
+    if path == Path::new("-") {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .context("reading stdin")?;
+        Ok(input)
+    } else {
+        fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))
+    }
+}
+
+/// An input's text, either mapped from its file or read into memory.
+pub enum Text {
+    Mapped(Mmap),
+    Owned(String),
+}
+
+impl Deref for Text {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        match self {
+            // SAFETY: checked to be UTF-8 when it was mapped.
+            Text::Mapped(map) => unsafe { std::str::from_utf8_unchecked(map) },
+            Text::Owned(text) => text,
+        }
+    }
+}
+
+/// Map the input at `path` into memory, or read stdin if it's `-`.
+pub fn map(path: impl AsRef<Path>) -> Result<Text> {
+    let path = path.as_ref();
+    if path == Path::new("-") {
+        return read(path).map(Text::Owned);
+    }
+    let context = || format!("reading {}", path.display());
+    let file = File::open(path).with_context(context)?;
+    // SAFETY: the map is only read, and the inputs are not modified while a day is solving
+    // them. If one is truncated meanwhile, reading past its new end is a fault, not a wrong
+    // answer.
+    let map = unsafe { Mmap::map(&file) }.with_context(context)?;
+    std::str::from_utf8(&map).with_context(context)?;
+    Ok(Text::Mapped(map))
+}
+
+/// Open the input at `path`, or stdin if it's `-`, for reading a piece at a time.
+pub fn open(path: impl AsRef<Path>) -> Result<Box<dyn BufRead>> {
+    let path = path.as_ref();
+    if path == Path::new("-") {
+        Ok(Box::new(io::stdin().lock()))
+    } else {
+        let file = File::open(path).with_context(|| format!("reading {}", path.display()))?;
+        Ok(Box::new(BufReader::with_capacity(1 << 16, file)))
+    }
+}
@@ -0,0 +1,29 @@
+pub mod alloc;
+#[cfg(feature = "animate")]
+pub mod animate;
+#[cfg(feature = "bench")]
+pub mod bench;
+pub mod cycle;
+pub mod direction;
+#[cfg(feature = "generate")]
+pub mod generate;
+pub mod grid;
+pub mod input;
+pub mod params;
+pub mod parse;
+pub mod part;
+pub mod point;
+pub mod render;
+pub mod report;
+pub mod shortest_path;
+pub mod solution;
+pub mod stream;
+pub mod trace;
+
+pub use direction::{Direction, Direction8, Turn};
+pub use grid::Grid;
+pub use parse::ParseError;
+pub use part::Part;
+pub use point::Point;
+pub use report::{solve, Report};
+pub use solution::{run, Answer, Solution, Unsolved};
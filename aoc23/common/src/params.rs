@@ -0,0 +1,321 @@
+//! The tunable constants of each day's puzzle, like how many steps to take or how far space
+//! expands, so they can be changed from the command line with `--param NAME=VALUE`.
+//!
+//! A day declares its constants as [`Solution::PARAMS`], each with a default and the part it
+//! tunes, and reads them in [`Solution::part_one_with`] and [`Solution::part_two_with`].
+
+use crate::part::Part;
+use crate::solution::Solution;
+use anyhow::{anyhow, bail, Result};
+use clap::{CommandFactory, FromArgMatches};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// A tunable constant of a day's puzzle.
+#[derive(Debug, Clone, Copy)]
+pub struct Param {
+    pub name: &'static str,
+    /// The part it tunes, or `None` for both.
+    pub part: Option<Part>,
+    pub default: &'static str,
+    pub help: &'static str,
+    /// Whether a value parses as the parameter's type.
+    valid: fn(&str) -> bool,
+    /// The least value it may have, if it's a number with a least value that makes sense.
+    min: Option<i128>,
+    /// The greatest value it may have, likewise.
+    max: Option<i128>,
+    /// Another parameter of the same part, the value of which this one's may be at most.
+    no_more_than: Option<&'static str>,
+}
+
+impl Param {
+    /// A parameter of type `T` for both parts.
+    pub const fn new<T: FromStr>(
+        name: &'static str,
+        default: &'static str,
+        help: &'static str,
+    ) -> Param {
+        Param {
+            name,
+            part: None,
+            default,
+            help,
+            valid: parses::<T>,
+            min: None,
+            max: None,
+            no_more_than: None,
+        }
+    }
+
+    /// This parameter, which is a number, allowed to be no less than `min`.
+    pub const fn at_least(self, min: i128) -> Param {
+        Param {
+            min: Some(min),
+            ..self
+        }
+    }
+
+    /// This parameter, which is a number, allowed to be no more than `max`.
+    pub const fn at_most(self, max: i128) -> Param {
+        Param {
+            max: Some(max),
+            ..self
+        }
+    }
+
+    /// This parameter, which is a number, allowed to be no more than the parameter `other` of
+    /// the same part.
+    pub const fn no_more_than(self, other: &'static str) -> Param {
+        Param {
+            no_more_than: Some(other),
+            ..self
+        }
+    }
+
+    /// This parameter, for `part` alone. Each part may have its own parameter of the same name.
+    pub const fn part(self, part: Part) -> Param {
+        Param {
+            part: Some(part),
+            ..self
+        }
+    }
+
+    fn applies_to(&self, part: Part) -> bool {
+        self.part.is_none_or(|p| p == part)
+    }
+}
+
+fn parses<T: FromStr>(value: &str) -> bool {
+    value.parse::<T>().is_ok()
+}
+
+/// A value given for a parameter on the command line: `NAME=VALUE` for every part with a
+/// parameter of that name, or `PART.NAME=VALUE` for one part, like `two.steps=100`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Override {
+    pub part: Option<Part>,
+    pub name: String,
+    pub value: String,
+}
+
+impl FromStr for Override {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Override, String> {
+        let (name, value) = s
+            .split_once('=')
+            .ok_or_else(|| format!("expected NAME=VALUE, found {}", s))?;
+        let (part, name) = match name.split_once('.') {
+            Some((part, name)) => (Some(part.parse()?), name),
+            None => (None, name),
+        };
+        Ok(Override {
+            part,
+            name: name.trim().to_string(),
+            value: value.trim().to_string(),
+        })
+    }
+}
+
+/// The values of a day's parameters for one part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Params {
+    values: HashMap<&'static str, String>,
+}
+
+impl Params {
+    /// The defaults of the parameters `declared` for `part`.
+    pub fn defaults(declared: &[Param], part: Part) -> Params {
+        Params {
+            values: declared
+                .iter()
+                .filter(|param| param.applies_to(part))
+                .map(|param| (param.name, param.default.to_string()))
+                .collect(),
+        }
+    }
+
+    /// The parameters `declared` for `part`, with `overrides` applied. It's an error to override
+    /// a parameter that isn't declared for the part given, or for any part if none is, to give a
+    /// value that doesn't parse or is out of range, or for the values to break a parameter's
+    /// [`Param::no_more_than`].
+    pub fn resolve(declared: &[Param], part: Part, overrides: &[Override]) -> Result<Params> {
+        let mut params = Params::defaults(declared, part);
+        for o in overrides {
+            let matching =
+                |param: &&Param| param.name == o.name && o.part.is_none_or(|p| param.applies_to(p));
+            let Some(param) = declared.iter().find(matching) else {
+                return Err(match o.part {
+                    Some(p) => anyhow!("part {} has no parameter {}", p, o.name),
+                    None => anyhow!("no parameter {}", o.name),
+                });
+            };
+            if !(param.valid)(&o.value) {
+                bail!("invalid value for {}: {}", o.name, o.value);
+            }
+            let number = o.value.parse::<i128>().ok();
+            if let Some(min) = param.min.filter(|&min| number.is_some_and(|n| n < min)) {
+                bail!("{} must be at least {}, not {}", o.name, min, o.value);
+            }
+            if let Some(max) = param.max.filter(|&max| number.is_some_and(|n| n > max)) {
+                bail!("{} must be at most {}, not {}", o.name, max, o.value);
+            }
+            if o.part.is_none_or(|p| p == part) && params.values.contains_key(o.name.as_str()) {
+                params.values.insert(param.name, o.value.clone());
+            }
+        }
+        for param in declared.iter().filter(|param| param.applies_to(part)) {
+            let Some(other) = param.no_more_than else {
+                continue;
+            };
+            let (value, limit) = (&params.values[param.name], &params.values[other]);
+            if value.parse::<i128>()? > limit.parse::<i128>()? {
+                bail!(
+                    "{} must be at most {} ({}) in part {}, not {}",
+                    param.name,
+                    other,
+                    limit,
+                    part,
+                    value
+                );
+            }
+        }
+        Ok(params)
+    }
+
+    /// The value of the parameter `name`.
+    ///
+    /// # Panics
+    ///
+    /// If the part has no parameter `name`, or its value isn't a `T`: a day asking for one it
+    /// didn't declare, or as another type.
+    pub fn get<T: FromStr>(&self, name: &str) -> T {
+        self.values
+            .get(name)
+            .and_then(|value| value.parse().ok())
+            .unwrap_or_else(|| panic!("no parameter {} of this type", name))
+    }
+}
+
+/// The heading of a list of parameters in `--help`.
+pub const HELP_HEADING: &str = "Parameters (set with --param [PART.]NAME=VALUE):";
+
+/// A list of the parameters `declared`, for `--help`.
+pub fn help(declared: &[Param]) -> String {
+    let list = match declared {
+        [] => "  none\n".to_string(),
+        _ => list(declared, "  "),
+    };
+    format!("{}\n{}", HELP_HEADING, list)
+}
+
+/// Each of the parameters `declared` with its default and what it's for, indented by `indent`.
+pub fn list(declared: &[Param], indent: &str) -> String {
+    let mut list = String::new();
+    for param in declared {
+        let part = match param.part {
+            Some(part) => format!(" (part {})", part),
+            None => String::new(),
+        };
+        list.push_str(&format!(
+            "{}{}={}{}\n{}        {}\n",
+            indent, param.name, param.default, part, indent, param.help
+        ));
+    }
+    list
+}
+
+/// The command-line arguments for choosing the parts to solve and setting parameters, to
+/// `#[command(flatten)]` into a binary's arguments.
+#[derive(Debug, Clone, Default, clap::Args)]
+#[command(about = None, long_about = None)]
+pub struct ParamArgs {
+    /// Only solve this part (1 or 2)
+    #[arg(short, long)]
+    pub part: Option<Part>,
+
+    /// Set a parameter of the puzzle, for both parts or just one, as listed below
+    #[arg(long = "param", value_name = "[PART.]NAME=VALUE")]
+    pub params: Vec<Override>,
+}
+
+impl ParamArgs {
+    /// The parts chosen: one, or both.
+    pub fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => Part::ALL.to_vec(),
+        }
+    }
+}
+
+/// Parse the command line as `A`, listing `S`'s parameters at the end of `--help`.
+pub fn parse_args<A: CommandFactory + FromArgMatches, S: Solution>() -> A {
+    let mut matches = A::command().after_help(help(S::PARAMS)).get_matches();
+    A::from_arg_matches_mut(&mut matches).unwrap_or_else(|err| err.exit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PARAMS: &[Param] = &[
+        Param::new::<usize>("steps", "64", "Steps to take").part(Part::One),
+        Param::new::<usize>("steps", "1000", "Steps to take").part(Part::Two),
+        Param::new::<i32>("offset", "0", "Where to start"),
+        Param::new::<usize>("least", "1", "Fewest to take")
+            .at_least(1)
+            .no_more_than("most"),
+        Param::new::<usize>("most", "3", "Most to take").at_most(10),
+    ];
+
+    fn overrides(args: &[&str]) -> Vec<Override> {
+        args.iter().map(|arg| arg.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn defaults_and_overrides() {
+        let one = Params::defaults(PARAMS, Part::One);
+        assert_eq!(one.get::<usize>("steps"), 64);
+        assert_eq!(one.get::<i32>("offset"), 0);
+
+        let given = overrides(&["two.steps=5", "offset = -3"]);
+        let one = Params::resolve(PARAMS, Part::One, &given).unwrap();
+        let two = Params::resolve(PARAMS, Part::Two, &given).unwrap();
+        assert_eq!(one.get::<usize>("steps"), 64);
+        assert_eq!(two.get::<usize>("steps"), 5);
+        assert_eq!(two.get::<i32>("offset"), -3);
+
+        let both = Params::resolve(PARAMS, Part::One, &overrides(&["steps=7"])).unwrap();
+        assert_eq!(both.get::<usize>("steps"), 7);
+    }
+
+    #[test]
+    fn bad_overrides() {
+        assert!("steps".parse::<Override>().is_err());
+        assert!("three.steps=1".parse::<Override>().is_err());
+        for bad in ["speed=1", "steps=-1", "one.speed=1"] {
+            assert!(Params::resolve(PARAMS, Part::One, &overrides(&[bad])).is_err());
+        }
+    }
+
+    #[test]
+    fn out_of_range_overrides() {
+        let resolve = |args| Params::resolve(PARAMS, Part::One, &overrides(args));
+        let err = resolve(&["least=0"]).unwrap_err();
+        assert_eq!(err.to_string(), "least must be at least 1, not 0");
+        let err = resolve(&["least=4"]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "least must be at most most (3) in part one, not 4"
+        );
+        assert!(resolve(&["most=0"]).is_err());
+        let err = resolve(&["most=11"]).unwrap_err();
+        assert_eq!(err.to_string(), "most must be at most 10, not 11");
+
+        let params = resolve(&["least=4", "most=4"]).unwrap();
+        assert_eq!(params.get::<usize>("least"), 4);
+        assert!(Params::resolve(PARAMS, Part::Two, &overrides(&["one.least=3"])).is_ok());
+    }
+}
@@ -0,0 +1,347 @@
+//! Parse errors that point at the offending line and column of the puzzle input.
+//!
+//! The nom parsers use [`IResult`] in place of nom's own, and [`parse_all`] to run the top-level
+//! parser and turn any failure into a [`ParseError`]. Hand-written parsers build a [`ParseError`]
+//! directly.
+
+use nom::error::{ErrorKind, FromExternalError};
+use std::fmt::Display;
+
+/// A parse failure, located in the input. Lines and columns count from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, message: impl Into<String>) -> Self {
+        ParseError {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// An error at byte `offset` of `input`.
+    pub fn at(input: &str, offset: usize, message: impl Into<String>) -> Self {
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        ParseError::new(
+            before.matches('\n').count() + 1,
+            before[line_start..].chars().count() + 1,
+            message,
+        )
+    }
+
+    /// An error at the start of `rest`, which must be a suffix of `input`.
+    pub fn at_rest(input: &str, rest: &str, message: impl Into<String>) -> Self {
+        ParseError::at(input, input.len() - rest.len(), message)
+    }
+
+    /// An error saying what was expected at the start of `rest`, and what was found instead.
+    pub fn expected(input: &str, rest: &str, expected: impl Display) -> Self {
+        ParseError::at_rest(
+            input,
+            rest,
+            format!("expected {}, found {}", expected, found(rest)),
+        )
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The nom error type: where a parser failed, and what it expected to find there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error<I> {
+    input: I,
+    reason: Reason,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Reason {
+    Expected(Vec<String>),
+    Message(String),
+}
+
+/// nom's `IResult`, with [`Error`] as the error type.
+pub type IResult<I, O> = nom::IResult<I, O, Error<I>>;
+
+impl<'a> Error<&'a str> {
+    pub fn expected(input: &'a str, expected: impl Display) -> Self {
+        Error {
+            input,
+            reason: Reason::Expected(vec![expected.to_string()]),
+        }
+    }
+
+    pub fn message(input: &'a str, message: impl Display) -> Self {
+        Error {
+            input,
+            reason: Reason::Message(message.to_string()),
+        }
+    }
+
+    /// Locate this error in `input`, the string the top-level parser was given.
+    pub fn locate(&self, input: &str) -> ParseError {
+        match &self.reason {
+            Reason::Expected(expected) => {
+                ParseError::expected(input, self.input, alternatives(expected))
+            }
+            Reason::Message(message) => ParseError::at_rest(input, self.input, message),
+        }
+    }
+}
+
+impl<'a> nom::error::ParseError<&'a str> for Error<&'a str> {
+    fn from_error_kind(input: &'a str, kind: ErrorKind) -> Self {
+        Error::expected(input, describe(kind))
+    }
+
+    // Keep the innermost error, which says the most about what went wrong.
+    fn append(_input: &'a str, _kind: ErrorKind, other: Self) -> Self {
+        other
+    }
+
+    fn from_char(input: &'a str, c: char) -> Self {
+        Error::expected(input, format!("{:?}", c))
+    }
+
+    // Of two failed alternatives, report the one that got furthest. If they failed at the same
+    // place, report everything either of them expected.
+    fn or(self, other: Self) -> Self {
+        if self.input.len() != other.input.len() {
+            return if self.input.len() < other.input.len() {
+                self
+            } else {
+                other
+            };
+        }
+        match (self.reason, other.reason) {
+            (Reason::Expected(mut ours), Reason::Expected(theirs)) => {
+                for expected in theirs {
+                    if !ours.contains(&expected) {
+                        ours.push(expected);
+                    }
+                }
+                Error {
+                    input: self.input,
+                    reason: Reason::Expected(ours),
+                }
+            }
+            (_, reason) => Error {
+                input: other.input,
+                reason,
+            },
+        }
+    }
+}
+
+impl<'a, E: Display> FromExternalError<&'a str, E> for Error<&'a str> {
+    fn from_external_error(input: &'a str, _kind: ErrorKind, e: E) -> Self {
+        Error::message(input, e)
+    }
+}
+
+// A context names what was expected where a parser failed outright, but a more specific error
+// from further in is kept.
+impl<'a> nom::error::ContextError<&'a str> for Error<&'a str> {
+    fn add_context(input: &'a str, ctx: &'static str, other: Self) -> Self {
+        if other.input.len() == input.len() {
+            Error::expected(input, ctx)
+        } else {
+            other
+        }
+    }
+}
+
+/// Like nom's `tag`, but the error names the token that was expected.
+pub fn tag<'a>(token: &'static str) -> impl Fn(&'a str) -> IResult<&'a str, &'a str> {
+    move |input: &'a str| match input.strip_prefix(token) {
+        Some(rest) => Ok((rest, &input[..input.len() - rest.len()])),
+        None => Err(nom::Err::Error(Error::expected(
+            input,
+            format!("{:?}", token),
+        ))),
+    }
+}
+
+/// One `item` per line, for as many lines as there are before a blank line or the end of input.
+///
+/// Unlike `separated_list1(line_ending, item)`, a line that `item` fails on, or doesn't consume
+/// all of, is an error rather than the end of the list.
+pub fn lines<'a, O>(
+    mut item: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    move |input: &'a str| {
+        let mut items = Vec::new();
+        let mut rest = input;
+        loop {
+            let (after, output) = item(rest)?;
+            items.push(output);
+            let next = after
+                .strip_prefix("\r\n")
+                .or_else(|| after.strip_prefix('\n'));
+            match next {
+                Some(next) if !next.is_empty() && !next.starts_with(['\r', '\n']) => rest = next,
+                Some(_) => return Ok((after, items)),
+                None if after.is_empty() => return Ok((after, items)),
+                None => return Err(nom::Err::Error(Error::expected(after, "end of line"))),
+            }
+        }
+    }
+}
+
+/// Like nom's `one_of`, but the error lists the characters that were allowed.
+pub fn one_of<'a>(chars: &'static str) -> impl Fn(&'a str) -> IResult<&'a str, char> {
+    move |input: &'a str| match input.chars().next() {
+        Some(c) if chars.contains(c) => Ok((&input[c.len_utf8()..], c)),
+        _ => Err(nom::Err::Error(Error::expected(
+            input,
+            format!("one of {:?}", chars),
+        ))),
+    }
+}
+
+/// Run `parser` over the whole of `input`. Anything but whitespace left over is an error.
+pub fn parse_all<'a, O>(
+    input: &'a str,
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> Result<O, ParseError> {
+    match parser(input) {
+        Ok((rest, output)) if rest.trim().is_empty() => Ok(output),
+        Ok((rest, _)) => Err(ParseError::expected(
+            input,
+            rest.trim_start(),
+            "end of input",
+        )),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => Err(e.locate(input)),
+        Err(nom::Err::Incomplete(_)) => Err(ParseError::at(
+            input,
+            input.len(),
+            "unexpected end of input",
+        )),
+    }
+}
+
+/// A character that isn't one of the `expected` ones, for `TryFrom<char>` conversions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BadChar {
+    pub found: char,
+    pub expected: &'static str,
+}
+
+impl Display for BadChar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "expected one of {:?}, found {:?}",
+            self.expected, self.found
+        )
+    }
+}
+
+impl std::error::Error for BadChar {}
+
+fn describe(kind: ErrorKind) -> String {
+    match kind {
+        ErrorKind::Digit => "a number",
+        ErrorKind::Alpha => "a letter",
+        ErrorKind::AlphaNumeric => "a letter or digit",
+        ErrorKind::Space | ErrorKind::MultiSpace => "whitespace",
+        ErrorKind::CrLf => "a line break",
+        ErrorKind::Eof => "end of input",
+        _ => return kind.description().to_lowercase(),
+    }
+    .to_string()
+}
+
+fn alternatives(expected: &[String]) -> String {
+    match expected {
+        [] => "something else".to_string(),
+        [only] => only.clone(),
+        [init @ .., last] => format!("{} or {}", init.join(", "), last),
+    }
+}
+
+// A short description of what's at the start of `rest`, for error messages: the next word,
+// with any whitespace before it.
+fn found(rest: &str) -> String {
+    match rest.chars().next() {
+        None => "end of input".to_string(),
+        Some('\n' | '\r') => "end of line".to_string(),
+        Some(_) => {
+            let line = rest.lines().next().unwrap_or_default();
+            let indent = line.len() - line.trim_start().len();
+            let word_len = line[indent..].find(char::is_whitespace);
+            let word = &line[..indent + word_len.unwrap_or(line.len() - indent)];
+            format!("{:?}", word.chars().take(16).collect::<String>())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::branch::alt;
+    use nom::character::complete::{line_ending, u32 as nom32};
+    use nom::multi::separated_list1;
+    use nom::sequence::{pair, preceded};
+
+    fn item(input: &str) -> IResult<&str, u32> {
+        preceded(alt((tag("red "), tag("blue "))), nom32)(input)
+    }
+
+    fn sections(input: &str) -> IResult<&str, Vec<Vec<u32>>> {
+        separated_list1(pair(line_ending, line_ending), lines(item))(input)
+    }
+
+    #[test]
+    fn located_errors() {
+        let items = lines(item);
+        assert_eq!(parse_all("red 1\nblue 2\n", items), Ok(vec![1, 2]));
+        assert_eq!(
+            parse_all("red 1\nblue x\n", lines(item))
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 6: expected a number, found \"x\""
+        );
+        assert_eq!(
+            parse_all("red 1\ngreen 2", lines(item))
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 1: expected \"red \" or \"blue \", found \"green\""
+        );
+        assert_eq!(
+            parse_all("red 1 2\nblue 2", lines(item))
+                .unwrap_err()
+                .to_string(),
+            "line 1, column 6: expected end of line, found \" 2\""
+        );
+        assert_eq!(
+            parse_all("red 1\n\nblue 2", lines(item))
+                .unwrap_err()
+                .to_string(),
+            "line 3, column 1: expected end of input, found \"blue\""
+        );
+    }
+
+    #[test]
+    fn blank_line_ends_lines() {
+        assert_eq!(
+            parse_all("red 1\r\nblue 2\r\n\r\nred 3\r\n", sections),
+            Ok(vec![vec![1, 2], vec![3]])
+        );
+    }
+}
@@ -0,0 +1,123 @@
+use crate::direction::{Direction, Direction8};
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Mul, Sub};
+
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> Display for Point<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl<T> Add<Point<T>> for Point<T>
+where
+    T: Add<Output = T>,
+{
+    type Output = Point<T>;
+
+    fn add(self, rhs: Point<T>) -> Self::Output {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T> AddAssign<Point<T>> for Point<T>
+where
+    T: AddAssign,
+{
+    fn add_assign(&mut self, rhs: Point<T>) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T> Sub<Point<T>> for Point<T>
+where
+    T: Sub<Output = T>,
+{
+    type Output = Point<T>;
+
+    fn sub(self, rhs: Point<T>) -> Self::Output {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T> Mul<T> for Point<T>
+where
+    T: Mul<Output = T> + Copy,
+{
+    type Output = Point<T>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Point::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl Point<usize> {
+    /// The point one step away in `dir`, if it is still inside a `width` x `height` grid.
+    pub fn step(self, dir: Direction, width: usize, height: usize) -> Option<Self> {
+        self.step8(dir.into(), width, height)
+    }
+
+    /// The point one step away in `dir`, if it is still inside a `width` x `height` grid.
+    pub fn step8(self, dir: Direction8, width: usize, height: usize) -> Option<Self> {
+        let delta = dir.delta::<i8>();
+        let x = self.x.checked_add_signed(delta.x as isize)?;
+        let y = self.y.checked_add_signed(delta.y as isize)?;
+        (x < width && y < height).then_some(Point::new(x, y))
+    }
+
+    /// The orthogonal neighbours of this point inside a `width` x `height` grid.
+    pub fn neighbors(self, width: usize, height: usize) -> impl Iterator<Item = Self> {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(dir, width, height))
+    }
+
+    /// The orthogonal and diagonal neighbours of this point inside a `width` x `height` grid.
+    pub fn neighbors8(self, width: usize, height: usize) -> impl Iterator<Item = Self> {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step8(dir, width, height))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let p = Point::new(3i64, -2);
+        assert_eq!(p + Point::new(1, 1), Point::new(4, -1));
+        assert_eq!(p - Point::new(1, 1), Point::new(2, -3));
+        assert_eq!(p * 2, Point::new(6, -4));
+        assert_eq!(p.to_string(), "(3, -2)");
+    }
+
+    #[test]
+    fn bounded_neighbors() {
+        let corner = Point::new(0usize, 0);
+        assert_eq!(
+            corner.neighbors(3, 3).collect::<Vec<_>>(),
+            vec![Point::new(0, 1), Point::new(1, 0)]
+        );
+        assert_eq!(Point::new(1usize, 1).neighbors(3, 3).count(), 4);
+        assert_eq!(Point::new(1usize, 1).neighbors8(3, 3).count(), 8);
+        assert_eq!(Point::new(2usize, 2).neighbors8(3, 3).count(), 3);
+        assert_eq!(Point::new(2usize, 0).step(Direction::Right, 3, 3), None);
+    }
+}
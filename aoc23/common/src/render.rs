@@ -0,0 +1,394 @@
+//! Drawing grids: as coloured text for the terminal, or as PPM or SVG images.
+//!
+//! A [`Canvas`] is made from a [`Grid`] and a mapping from each cell to a [`Style`], then
+//! marked up with highlighted cells and paths before it is written out.
+
+use crate::point::Point;
+use anyhow::{Context, Result};
+use clap::ValueEnum;
+use grid::Grid;
+use std::fmt::Write;
+use std::fs;
+use std::path::PathBuf;
+
+/// Pixels along each side of a cell in a PPM image or an animation.
+pub const PIXEL_SCALE: usize = 4;
+
+/// Pixels along each side of a cell in an SVG image, at its natural size.
+const SVG_SCALE: usize = 12;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Color {
+    pub const BLACK: Color = Color::rgb(0, 0, 0);
+    pub const WHITE: Color = Color::rgb(255, 255, 255);
+    pub const GREY: Color = Color::rgb(128, 128, 128);
+    pub const DARK_GREY: Color = Color::rgb(48, 48, 48);
+    pub const RED: Color = Color::rgb(220, 50, 47);
+    pub const GREEN: Color = Color::rgb(64, 160, 43);
+    pub const BLUE: Color = Color::rgb(38, 139, 210);
+    pub const YELLOW: Color = Color::rgb(223, 180, 0);
+
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Color {
+        Color { r, g, b }
+    }
+
+    /// A colour between `self` at 0.0 and `other` at 1.0, for heat maps.
+    pub fn blend(self, other: Color, t: f64) -> Color {
+        let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t.clamp(0.0, 1.0)) as u8;
+        Color::rgb(
+            mix(self.r, other.r),
+            mix(self.g, other.g),
+            mix(self.b, other.b),
+        )
+    }
+
+    fn hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
+/// How a cell is drawn: its glyph in the terminal and SVG, and the colour of the glyph, or of
+/// the whole cell in a PPM image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Style {
+    pub glyph: char,
+    pub color: Color,
+}
+
+impl Style {
+    pub const fn new(glyph: char, color: Color) -> Style {
+        Style { glyph, color }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Cell {
+    style: Style,
+    highlight: Option<Color>,
+    path: Option<Color>,
+}
+
+impl Cell {
+    /// The background in the terminal and PPM, where paths are drawn over highlights.
+    fn background(&self) -> Option<Color> {
+        self.path.or(self.highlight)
+    }
+}
+
+/// A grid ready to be drawn.
+#[derive(Debug, Clone)]
+pub struct Canvas {
+    cells: Grid<Cell>,
+    paths: Vec<(Vec<Point<usize>>, Color)>,
+}
+
+impl Canvas {
+    pub fn new<T>(grid: &Grid<T>, mut style: impl FnMut(&T) -> Style) -> Canvas {
+        let cells = grid
+            .iter()
+            .map(|cell| Cell {
+                style: style(cell),
+                highlight: None,
+                path: None,
+            })
+            .collect();
+        Canvas {
+            cells: Grid::from_vec(cells, grid.cols()),
+            paths: Vec::new(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.cells.cols()
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.rows()
+    }
+
+    /// Fill the background of `cells` with `color`, over any earlier highlight. Cells outside
+    /// the canvas are ignored.
+    pub fn highlight(
+        &mut self,
+        cells: impl IntoIterator<Item = Point<usize>>,
+        color: Color,
+    ) -> &mut Self {
+        for p in cells {
+            if let Some(cell) = self.cells.get_mut(p.y, p.x) {
+                cell.highlight = Some(color);
+            }
+        }
+        self
+    }
+
+    /// The part of the canvas `width` x `height` cells from `(x, y)`, clipped to the canvas.
+    /// Paths keep only their cells inside it.
+    pub fn crop(&self, x: usize, y: usize, width: usize, height: usize) -> Canvas {
+        let (x, y) = (x.min(self.width()), y.min(self.height()));
+        let width = width.min(self.width() - x);
+        let height = height.min(self.height() - y);
+        let cells = (y..y + height)
+            .flat_map(|row| (x..x + width).map(move |col| self.cells[(row, col)]))
+            .collect();
+        let inside =
+            |p: &&Point<usize>| (x..x + width).contains(&p.x) && (y..y + height).contains(&p.y);
+        let paths = self
+            .paths
+            .iter()
+            .map(|(cells, color)| {
+                let cells = cells
+                    .iter()
+                    .filter(inside)
+                    .map(|p| Point::new(p.x - x, p.y - y))
+                    .collect();
+                (cells, *color)
+            })
+            .collect();
+        Canvas {
+            cells: Grid::from_vec(cells, width),
+            paths,
+        }
+    }
+
+    /// Draw a path through `cells` in order. It's a line in SVG, and fills the background of
+    /// its cells in the other formats, over any highlight.
+    pub fn path(&mut self, cells: Vec<Point<usize>>, color: Color) -> &mut Self {
+        for p in &cells {
+            if let Some(cell) = self.cells.get_mut(p.y, p.x) {
+                cell.path = Some(color);
+            }
+        }
+        self.paths.push((cells, color));
+        self
+    }
+
+    /// The glyphs in 24-bit colour, with highlights as their background, one line per row.
+    pub fn ansi(&self) -> String {
+        let mut out = String::new();
+        for row in self.cells.iter_rows() {
+            let mut current = None;
+            for cell in row {
+                let colors = (cell.style.color, cell.background());
+                if current != Some(colors) {
+                    let Color { r, g, b } = cell.style.color;
+                    write!(out, "\x1b[0;38;2;{};{};{}", r, g, b).unwrap();
+                    if let Some(Color { r, g, b }) = cell.background() {
+                        write!(out, ";48;2;{};{};{}", r, g, b).unwrap();
+                    }
+                    out.push('m');
+                    current = Some(colors);
+                }
+                out.push(cell.style.glyph);
+            }
+            out.push_str("\x1b[0m\n");
+        }
+        out
+    }
+
+    /// A binary PPM image with each cell a square of its background colour, or its own.
+    pub fn ppm(&self) -> Vec<u8> {
+        let (width, height) = (self.width() * PIXEL_SCALE, self.height() * PIXEL_SCALE);
+        let mut out = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+        out.extend(self.pixels(PIXEL_SCALE));
+        out
+    }
+
+    /// The RGB bytes of an image with each cell a `scale` x `scale` square of its background
+    /// colour, or its own, row by row.
+    pub fn pixels(&self, scale: usize) -> Vec<u8> {
+        let mut out = Vec::with_capacity(self.width() * self.height() * scale * scale * 3);
+        for row in self.cells.iter_rows() {
+            let pixels: Vec<u8> = row
+                .flat_map(|cell| {
+                    let Color { r, g, b } = cell.background().unwrap_or(cell.style.color);
+                    [r, g, b].repeat(scale)
+                })
+                .collect();
+            for _ in 0..scale {
+                out.extend_from_slice(&pixels);
+            }
+        }
+        out
+    }
+
+    /// An SVG image with a unit square for each cell, its glyph on top, and paths as lines
+    /// through the middle of their cells.
+    pub fn svg(&self) -> String {
+        let mut out = String::new();
+        writeln!(
+            out,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 {w} {h}" width="{}" height="{}">"#,
+            self.width() * SVG_SCALE,
+            self.height() * SVG_SCALE,
+            w = self.width(),
+            h = self.height(),
+        )
+        .unwrap();
+        writeln!(
+            out,
+            r#"<rect width="{}" height="{}" fill="black"/>"#,
+            self.width(),
+            self.height()
+        )
+        .unwrap();
+        for ((y, x), cell) in self.cells.indexed_iter() {
+            if let Some(highlight) = cell.highlight {
+                writeln!(
+                    out,
+                    r#"<rect x="{}" y="{}" width="1" height="1" fill="{}"/>"#,
+                    x,
+                    y,
+                    highlight.hex()
+                )
+                .unwrap();
+            }
+        }
+        writeln!(
+            out,
+            r#"<g font-family="monospace" font-size="0.8" text-anchor="middle" dominant-baseline="central">"#
+        )
+        .unwrap();
+        for ((y, x), cell) in self.cells.indexed_iter() {
+            if !cell.style.glyph.is_whitespace() {
+                writeln!(
+                    out,
+                    r#"<text x="{}.5" y="{}.5" fill="{}">{}</text>"#,
+                    x,
+                    y,
+                    cell.style.color.hex(),
+                    escape(cell.style.glyph)
+                )
+                .unwrap();
+            }
+        }
+        out.push_str("</g>\n");
+        for (cells, color) in &self.paths {
+            let points: Vec<String> = cells
+                .iter()
+                .map(|p| format!("{}.5,{}.5", p.x, p.y))
+                .collect();
+            writeln!(
+                out,
+                r#"<polyline points="{}" fill="none" stroke="{}" stroke-width="0.3" stroke-linejoin="round"/>"#,
+                points.join(" "),
+                color.hex()
+            )
+            .unwrap();
+        }
+        out.push_str("</svg>\n");
+        out
+    }
+}
+
+fn escape(glyph: char) -> String {
+    match glyph {
+        '<' => "&lt;".to_string(),
+        '>' => "&gt;".to_string(),
+        '&' => "&amp;".to_string(),
+        c => c.to_string(),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    Ansi,
+    Ppm,
+    Svg,
+}
+
+/// The command-line arguments for rendering a day's grid, to `#[command(flatten)]` into its
+/// binary's arguments.
+#[derive(Debug, Clone, clap::Args)]
+#[command(about = None, long_about = None)]
+pub struct RenderArgs {
+    /// Draw the puzzle: ansi prints it, ppm and svg write an image
+    #[arg(long, value_enum)]
+    pub render: Option<Format>,
+
+    /// Where to write the image [default: dayN.ppm or dayN.svg]
+    #[arg(long, requires = "render")]
+    pub render_output: Option<PathBuf>,
+}
+
+impl RenderArgs {
+    /// Draw `canvas` in the chosen format, if one was chosen. An image file is written, saying
+    /// where on stderr, to keep stdout for the answers.
+    pub fn write(&self, day: u8, canvas: impl FnOnce() -> Result<Canvas>) -> Result<()> {
+        let Some(format) = self.render else {
+            return Ok(());
+        };
+        let canvas = canvas().context("rendering")?;
+        let (contents, extension) = match format {
+            Format::Ansi => {
+                print!("{}", canvas.ansi());
+                return Ok(());
+            }
+            Format::Ppm => (canvas.ppm(), "ppm"),
+            Format::Svg => (canvas.svg().into_bytes(), "svg"),
+        };
+        let path = self
+            .render_output
+            .clone()
+            .unwrap_or_else(|| PathBuf::from(format!("day{}.{}", day, extension)));
+        fs::write(&path, contents).with_context(|| format!("writing {}", path.display()))?;
+        eprintln!("Wrote render to {}", path.display());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn canvas() -> Canvas {
+        let grid = Grid::from_vec(vec!['#', '.', '<', '#'], 2);
+        let mut canvas = Canvas::new(&grid, |&c| match c {
+            '#' => Style::new('#', Color::GREY),
+            c => Style::new(c, Color::WHITE),
+        });
+        canvas.highlight([Point::new(1, 0)], Color::RED);
+        canvas.path(vec![Point::new(0, 1), Point::new(1, 1)], Color::YELLOW);
+        canvas
+    }
+
+    #[test]
+    fn ansi_and_ppm() {
+        let canvas = canvas();
+        let ansi = canvas.ansi();
+        assert_eq!(ansi.lines().count(), 2);
+        assert!(
+            ansi.starts_with("\x1b[0;38;2;128;128;128m#\x1b[0;38;2;255;255;255;48;2;220;50;47m.")
+        );
+
+        let ppm = canvas.ppm();
+        let header = b"P6\n8 8\n255\n";
+        assert!(ppm.starts_with(header));
+        assert_eq!(ppm.len(), header.len() + 8 * 8 * 3);
+        // The top right cell is highlighted.
+        assert_eq!(ppm[header.len() + 4 * 3..][..3], [220, 50, 47]);
+    }
+
+    #[test]
+    fn svg() {
+        let svg = canvas().svg();
+        assert!(svg.contains(r#"viewBox="0 0 2 2""#));
+        assert!(svg.contains("&lt;"));
+        assert!(svg.contains(r#"<polyline points="0.5,1.5 1.5,1.5""#));
+        assert_eq!(svg.matches("<rect").count(), 2);
+        assert_eq!(svg.matches("<text").count(), 4);
+    }
+
+    #[test]
+    fn crop() {
+        let cropped = canvas().crop(1, 0, 5, 5);
+        assert_eq!((cropped.width(), cropped.height()), (1, 2));
+        assert_eq!(cropped.pixels(1), [220, 50, 47, 223, 180, 0]);
+        assert!(cropped.svg().contains(r#"<polyline points="0.5,1.5""#));
+    }
+}
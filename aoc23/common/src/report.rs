@@ -0,0 +1,133 @@
+//! Timed runs of a solution: the answers, and how long parsing and each part took and how much
+//! they allocated. Reports serialize to JSON, to compare runs across commits and machines.
+
+use crate::alloc::{self, Usage};
+use crate::params::{Override, Params};
+use crate::part::Part;
+use crate::solution::{parse, solve_part, unix_newlines, Solution};
+use anyhow::Result;
+use serde::{Serialize, Serializer};
+use std::time::{Duration, Instant};
+
+/// The result of parsing a puzzle input and solving some of its parts.
+#[derive(Debug, Clone, Serialize)]
+pub struct Report {
+    pub parse: Measurement,
+    pub parts: Vec<PartReport>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PartReport {
+    pub part: Part,
+    /// `None` if the part is [`Unsolved`](crate::Unsolved).
+    pub answer: Option<String>,
+    #[serde(flatten)]
+    pub measurement: Measurement,
+}
+
+/// How long one step took, and its memory use if allocations are being counted.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct Measurement {
+    #[serde(rename = "time_ns", serialize_with = "nanos")]
+    pub time: Duration,
+    #[serde(flatten)]
+    pub usage: Option<Usage>,
+}
+
+impl Report {
+    /// The answer to `part`, if it was solved.
+    pub fn answer(&self, part: Part) -> Option<&str> {
+        self.parts
+            .iter()
+            .find(|report| report.part == part)
+            .and_then(|report| report.answer.as_deref())
+    }
+
+    /// The time taken to parse and solve every part.
+    pub fn total_time(&self) -> Duration {
+        self.parse.time
+            + self
+                .parts
+                .iter()
+                .map(|part| part.measurement.time)
+                .sum::<Duration>()
+    }
+}
+
+/// Run `f`, measuring its time and memory use.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Measurement) {
+    let start = Instant::now();
+    let (output, usage) = alloc::track(f);
+    let time = start.elapsed();
+    (output, Measurement { time, usage })
+}
+
+/// Parse `input` and solve each of `parts` with their parameters' defaults changed by
+/// `overrides`, measuring each step.
+///
+/// As with [`run`](crate::run), `\r\n` line endings are converted to `\n` first.
+pub fn solve<S: Solution>(input: &str, parts: &[Part], overrides: &[Override]) -> Result<Report> {
+    let params = parts
+        .iter()
+        .map(|&part| Params::resolve(S::PARAMS, part, overrides))
+        .collect::<Result<Vec<_>>>()?;
+    let input = unix_newlines(input);
+    let (parsed, parse) = measure(|| parse::<S>(&input));
+    let parsed = parsed?;
+    let parts = parts
+        .iter()
+        .zip(&params)
+        .map(|(&part, params)| {
+            let (answer, measurement) = measure(|| solve_part::<S>(&parsed, part, params));
+            PartReport {
+                part,
+                answer,
+                measurement,
+            }
+        })
+        .collect();
+    Ok(Report { parse, parts })
+}
+
+fn nanos<S: Serializer>(time: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(time.as_nanos())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Unsolved;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<u32>;
+        type PartOne = u32;
+        type PartTwo = Unsolved;
+
+        fn parse(input: &str) -> Result<Vec<u32>> {
+            Ok(input.lines().map(str::parse).collect::<Result<_, _>>()?)
+        }
+
+        fn part_one(input: &Vec<u32>) -> u32 {
+            input.iter().sum()
+        }
+
+        fn part_two(_: &Vec<u32>) -> Unsolved {
+            Unsolved
+        }
+    }
+
+    #[test]
+    fn answers_and_json() {
+        let report = solve::<Sum>("1\r\n2\r\n3\r\n", &Part::ALL, &[]).unwrap();
+        assert_eq!(report.answer(Part::One), Some("6"));
+        assert_eq!(report.answer(Part::Two), None);
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["parts"][0]["part"], 1);
+        assert_eq!(json["parts"][0]["answer"], "6");
+        assert!(json["parts"][1]["answer"].is_null());
+        assert!(json["parse"]["time_ns"].is_u64());
+    }
+}
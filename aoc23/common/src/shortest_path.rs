@@ -0,0 +1,333 @@
+//! Least-cost paths through graphs given as a [`StateSpace`]: states numbered densely so the
+//! search can keep its bookkeeping in flat vectors, the steps from each state and what they
+//! cost, and which states are goals.
+//!
+//! The search runs on one of three [`Backend`]s. Dial's algorithm suits small integer step
+//! costs, like a grid of digits; Dijkstra's with a binary heap suits any costs; and A* also
+//! uses the space's [`heuristic`](StateSpace::heuristic) to head for the goal.
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, VecDeque};
+use tracing::{debug, info_span};
+
+/// A graph to search.
+pub trait StateSpace {
+    type State: Copy;
+
+    /// One more than the largest index of any state.
+    fn size(&self) -> usize;
+
+    /// A number identifying `state`, below [`size`](StateSpace::size).
+    fn index(&self, state: &Self::State) -> usize;
+
+    /// The states one step from `state`.
+    fn neighbors(&self, state: &Self::State) -> impl Iterator<Item = Self::State>;
+
+    /// The cost of the step from `from` to its neighbour `to`.
+    fn cost(&self, from: &Self::State, to: &Self::State) -> usize;
+
+    fn is_goal(&self, state: &Self::State) -> bool;
+
+    /// A lower bound on the cost from `state` to the nearest goal, for A*. It must never be
+    /// more than a step's cost plus the bound from the state after it.
+    fn heuristic(&self, _state: &Self::State) -> usize {
+        0
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Backend {
+    /// Dial's algorithm: Dijkstra's with a queue of buckets, one for each cost.
+    #[default]
+    Dial,
+    /// Dijkstra's algorithm with a binary heap.
+    Dijkstra,
+    /// A* with a binary heap.
+    AStar,
+}
+
+/// A least-cost path, from the start to a goal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S> {
+    pub cost: usize,
+    pub states: Vec<S>,
+}
+
+/// The least-cost path from `start` to any goal, if one can be reached.
+pub fn shortest_path<G: StateSpace>(
+    space: &G,
+    start: G::State,
+    backend: Backend,
+) -> Option<Path<G::State>> {
+    run(space, start, backend, true)
+}
+
+/// The cost of the least-cost path from `start` to any goal, if one can be reached. Quicker
+/// than [`shortest_path`], which has to remember how it reached every state.
+pub fn shortest_cost<G: StateSpace>(space: &G, start: G::State, backend: Backend) -> Option<usize> {
+    run(space, start, backend, false).map(|path| path.cost)
+}
+
+fn run<G: StateSpace>(
+    space: &G,
+    start: G::State,
+    backend: Backend,
+    track: bool,
+) -> Option<Path<G::State>> {
+    let _span = info_span!("shortest_path", ?backend, states = space.size()).entered();
+    match backend {
+        Backend::Dial => search(space, start, BucketQueue::new(), false, track),
+        Backend::Dijkstra => search(space, start, BinaryHeap::new(), false, track),
+        Backend::AStar => search(space, start, BinaryHeap::new(), true, track),
+    }
+}
+
+/// The states waiting to be visited, by priority.
+trait Frontier<T> {
+    fn push(&mut self, item: T, priority: usize);
+    fn pop(&mut self) -> Option<T>;
+}
+
+fn search<G: StateSpace>(
+    space: &G,
+    start: G::State,
+    mut frontier: impl Frontier<G::State>,
+    use_heuristic: bool,
+    track: bool,
+) -> Option<Path<G::State>> {
+    let estimate = |state: &G::State| {
+        if use_heuristic {
+            space.heuristic(state)
+        } else {
+            0
+        }
+    };
+
+    // The cost of reaching each state so far, and if tracking, the state it was reached from.
+    let mut costs = vec![usize::MAX; space.size()];
+    let mut came_from = vec![None; if track { space.size() } else { 0 }];
+    let mut done = vec![false; space.size()];
+
+    // How much work the search did, for tracing.
+    let (mut pushes, mut pops) = (1, 0);
+
+    costs[space.index(&start)] = 0;
+    frontier.push(start, estimate(&start));
+
+    while let Some(state) = frontier.pop() {
+        pops += 1;
+        let index = space.index(&state);
+        // States are queued again when a cheaper way to them is found, rather than moved.
+        if done[index] {
+            continue;
+        }
+        done[index] = true;
+
+        if space.is_goal(&state) {
+            debug!(pushes, pops, cost = costs[index], "reached a goal");
+            let mut states = vec![state];
+            while let Some(&Some(prev)) = came_from.get(space.index(states.last().unwrap())) {
+                states.push(prev);
+            }
+            states.reverse();
+            return Some(Path {
+                cost: costs[index],
+                states,
+            });
+        }
+
+        for next in space.neighbors(&state) {
+            let next_index = space.index(&next);
+            let cost = costs[index] + space.cost(&state, &next);
+            if cost < costs[next_index] {
+                costs[next_index] = cost;
+                if track {
+                    came_from[next_index] = Some(state);
+                }
+                frontier.push(next, cost + estimate(&next));
+                pushes += 1;
+            }
+        }
+    }
+
+    debug!(pushes, pops, "no goal reached");
+    None
+}
+
+/// A priority queue for small integer priorities that never go below the lowest in the
+/// queue: a bucket for each priority from the lowest up, rotated round as that rises.
+#[derive(Debug, Clone)]
+pub struct BucketQueue<T> {
+    size: usize,
+    base_priority: usize,
+    queues: VecDeque<Vec<T>>,
+}
+
+impl<T> BucketQueue<T> {
+    pub fn new() -> Self {
+        Self {
+            size: 0,
+            base_priority: 0,
+            queues: VecDeque::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// # Panics
+    ///
+    /// If `priority` is lower than that of the last item popped.
+    pub fn push(&mut self, item: T, priority: usize) {
+        let bucket = priority
+            .checked_sub(self.base_priority)
+            .expect("priority is below the queue's lowest");
+        if bucket >= self.queues.len() {
+            self.queues.resize_with(bucket + 1, Vec::new);
+        }
+        self.queues[bucket].push(item);
+        self.size += 1;
+    }
+
+    /// Remove an item with the lowest priority, returning it and its priority.
+    pub fn pop(&mut self) -> Option<(T, usize)> {
+        if self.is_empty() {
+            return None;
+        }
+        loop {
+            if let Some(item) = self.queues[0].pop() {
+                self.size -= 1;
+                return Some((item, self.base_priority));
+            }
+            // Keep the empty bucket's allocation for a higher priority.
+            self.queues.rotate_left(1);
+            self.base_priority += 1;
+        }
+    }
+}
+
+impl<T> Default for BucketQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Frontier<T> for BucketQueue<T> {
+    fn push(&mut self, item: T, priority: usize) {
+        BucketQueue::push(self, item, priority);
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        BucketQueue::pop(self).map(|(item, _)| item)
+    }
+}
+
+/// An item in a [`BinaryHeap`] frontier, ordered by its priority alone.
+struct Queued<T>(Reverse<usize>, T);
+
+impl<T> PartialEq for Queued<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T> Eq for Queued<T> {}
+
+impl<T> PartialOrd for Queued<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Queued<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<T> Frontier<T> for BinaryHeap<Queued<T>> {
+    fn push(&mut self, item: T, priority: usize) {
+        BinaryHeap::push(self, Queued(Reverse(priority), item));
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        BinaryHeap::pop(self).map(|Queued(_, item)| item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Walking along a line of positions 0 to 9: a step forward costs 1 and one back costs 3,
+    /// except that 4 to 5 costs 10. Jumping forward 2 from an even position costs 5.
+    struct Line;
+
+    impl StateSpace for Line {
+        type State = usize;
+
+        fn size(&self) -> usize {
+            10
+        }
+
+        fn index(&self, state: &usize) -> usize {
+            *state
+        }
+
+        fn neighbors(&self, &state: &usize) -> impl Iterator<Item = usize> {
+            let jump = state.is_multiple_of(2).then_some(state + 2);
+            [state.checked_sub(1), Some(state + 1), jump]
+                .into_iter()
+                .flatten()
+                .filter(|&next| next < 10)
+        }
+
+        fn cost(&self, &from: &usize, &to: &usize) -> usize {
+            match (from, to) {
+                (4, 5) => 10,
+                _ if to == from + 2 => 5,
+                _ if to > from => 1,
+                _ => 3,
+            }
+        }
+
+        fn is_goal(&self, &state: &usize) -> bool {
+            state == 9
+        }
+
+        fn heuristic(&self, &state: &usize) -> usize {
+            9 - state
+        }
+    }
+
+    #[test]
+    fn backends_agree() {
+        for backend in [Backend::Dial, Backend::Dijkstra, Backend::AStar] {
+            let path = shortest_path(&Line, 0, backend).unwrap();
+            assert_eq!(path.cost, 12, "{:?}", backend);
+            assert_eq!(shortest_cost(&Line, 0, backend), Some(12));
+            // Jumping over 4 to 5 is cheapest.
+            assert_eq!(path.states, [0, 1, 2, 3, 4, 6, 7, 8, 9], "{:?}", backend);
+        }
+    }
+
+    #[test]
+    fn bucket_queue() {
+        let mut queue = BucketQueue::new();
+        queue.push('a', 3);
+        queue.push('b', 0);
+        queue.push('c', 12);
+        assert_eq!(queue.pop(), Some(('b', 0)));
+        queue.push('d', 1);
+        assert_eq!(queue.len(), 3);
+        assert_eq!(queue.pop(), Some(('d', 1)));
+        assert_eq!(queue.pop(), Some(('a', 3)));
+        assert_eq!(queue.pop(), Some(('c', 12)));
+        assert_eq!(queue.pop(), None);
+    }
+}
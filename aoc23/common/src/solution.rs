@@ -0,0 +1,88 @@
+use crate::params::{Override, Param, Params};
+use crate::part::Part;
+use anyhow::Result;
+use std::borrow::Cow;
+use std::fmt::Display;
+use tracing::info_span;
+
+/// A day's puzzle: how to parse its input, and how to solve each part from the parsed input.
+pub trait Solution {
+    type Input;
+    type PartOne: Answer;
+    type PartTwo: Answer;
+
+    /// The puzzle's tunable constants, which the parts read from the [`Params`] they're given.
+    const PARAMS: &'static [Param] = &[];
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part_one(input: &Self::Input) -> Self::PartOne;
+    fn part_two(input: &Self::Input) -> Self::PartTwo;
+
+    /// Part one with its [`PARAMS`](Solution::PARAMS) set from `params`. Days with parameters
+    /// solve part one here, and have [`part_one`](Solution::part_one) use the defaults.
+    fn part_one_with(input: &Self::Input, _params: &Params) -> Self::PartOne {
+        Self::part_one(input)
+    }
+
+    /// Part two with its [`PARAMS`](Solution::PARAMS) set from `params`, as for part one.
+    fn part_two_with(input: &Self::Input, _params: &Params) -> Self::PartTwo {
+        Self::part_two(input)
+    }
+}
+
+/// Solve `part` of a parsed input, with `params` for that part.
+pub(crate) fn solve_part<S: Solution>(
+    input: &S::Input,
+    part: Part,
+    params: &Params,
+) -> Option<String> {
+    match part {
+        Part::One => info_span!("part_one").in_scope(|| S::part_one_with(input, params).answer()),
+        Part::Two => info_span!("part_two").in_scope(|| S::part_two_with(input, params).answer()),
+    }
+}
+
+/// Parse `input`, in a span for tracing.
+pub(crate) fn parse<S: Solution>(input: &str) -> Result<S::Input> {
+    info_span!("parse", bytes = input.len()).in_scope(|| S::parse(input))
+}
+
+/// The result of solving one part of a puzzle. Anything displayable is an answer.
+pub trait Answer {
+    fn answer(&self) -> Option<String>;
+}
+
+impl<T: Display> Answer for T {
+    fn answer(&self) -> Option<String> {
+        Some(self.to_string())
+    }
+}
+
+/// The answer to a part that hasn't been solved yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unsolved;
+
+impl Answer for Unsolved {
+    fn answer(&self) -> Option<String> {
+        None
+    }
+}
+
+/// Parse `input` and solve one part of it with its parameters' defaults changed by
+/// `overrides`, returning `None` if that part is [`Unsolved`].
+///
+/// `\r\n` line endings are converted to `\n` first, so the parsers only need to handle one.
+pub fn run<S: Solution>(input: &str, part: Part, overrides: &[Override]) -> Result<Option<String>> {
+    let params = Params::resolve(S::PARAMS, part, overrides)?;
+    let input = parse::<S>(&unix_newlines(input))?;
+    Ok(solve_part::<S>(&input, part, &params))
+}
+
+/// `input` with `\r\n` line endings converted to `\n`, only copied if it has any.
+pub(crate) fn unix_newlines(input: &str) -> Cow<'_, str> {
+    if input.contains('\r') {
+        Cow::Owned(input.replace("\r\n", "\n"))
+    } else {
+        Cow::Borrowed(input)
+    }
+}
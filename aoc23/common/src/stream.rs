@@ -0,0 +1,173 @@
+//! Solving a puzzle a record at a time as its input is read, for inputs too big to hold in
+//! memory.
+//!
+//! A [`Streaming`] solution folds each record (usually a line) of its input into a running
+//! state, and answers both parts from that state at the end. Only one record is held at a time,
+//! so memory use is bounded by the longest record and the state, however long the input.
+
+use crate::parse::ParseError;
+use crate::part::Part;
+use crate::report::{measure, PartReport, Report};
+use crate::solution::{Answer, Solution};
+use anyhow::{Context, Result};
+use std::io::BufRead;
+use tracing::info_span;
+
+/// A [`Solution`] that can also solve its input a record at a time.
+pub trait Streaming: Solution {
+    /// What's kept of the records so far.
+    type State: Default;
+
+    /// The byte that ends each record.
+    const SEPARATOR: u8 = b'\n';
+
+    /// Fold the next record into `state`. Records have trailing whitespace trimmed, and blank
+    /// ones are skipped. Any [`ParseError`] is taken to be located within the record.
+    fn feed(state: &mut Self::State, record: &str) -> Result<()>;
+
+    fn finish_one(state: &Self::State) -> Self::PartOne;
+    fn finish_two(state: &Self::State) -> Self::PartTwo;
+}
+
+/// Feed every record from `reader` into a new state.
+pub fn stream<S: Streaming>(reader: &mut dyn BufRead) -> Result<S::State> {
+    let mut state = S::State::default();
+    let mut buffer = Vec::new();
+    // Where the next record starts in the input, for locating errors.
+    let (mut line, mut column) = (1, 1);
+    loop {
+        buffer.clear();
+        if reader.read_until(S::SEPARATOR, &mut buffer)? == 0 {
+            return Ok(state);
+        }
+        let record = std::str::from_utf8(&buffer)
+            .with_context(|| format!("line {}: invalid UTF-8", line))?;
+        let record = record.strip_suffix(S::SEPARATOR as char).unwrap_or(record);
+
+        let trimmed = record.trim_end();
+        if !trimmed.is_empty() {
+            S::feed(&mut state, trimmed).map_err(|err| match err.downcast::<ParseError>() {
+                Ok(err) => relocate(err, line, column).into(),
+                Err(err) => err.context(format!("line {}", line)),
+            })?;
+        }
+
+        match record.rfind('\n') {
+            Some(last) => {
+                line += record.matches('\n').count();
+                column = record[last + 1..].chars().count() + 1;
+            }
+            None => column += record.chars().count(),
+        }
+        if S::SEPARATOR == b'\n' {
+            (line, column) = (line + 1, 1);
+        } else {
+            column += 1;
+        }
+    }
+}
+
+/// `err`, located in a record, moved to where the record starts in the whole input.
+fn relocate(err: ParseError, line: usize, column: usize) -> ParseError {
+    ParseError {
+        column: if err.line == 1 {
+            err.column + column - 1
+        } else {
+            err.column
+        },
+        line: err.line + line - 1,
+        message: err.message,
+    }
+}
+
+/// Stream the input from `reader` and solve each of `parts`, measuring each step. Reading and
+/// folding in the records counts as parsing.
+///
+/// Unlike [`solve`](crate::solve), `\r\n` line endings are handled by trimming each record.
+pub fn solve<S: Streaming>(reader: &mut dyn BufRead, parts: &[Part]) -> Result<Report> {
+    let (state, parse) = measure(|| info_span!("stream").in_scope(|| stream::<S>(reader)));
+    let state = state?;
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let (answer, measurement) = measure(|| match part {
+                Part::One => info_span!("part_one").in_scope(|| S::finish_one(&state).answer()),
+                Part::Two => info_span!("part_two").in_scope(|| S::finish_two(&state).answer()),
+            });
+            PartReport {
+                part,
+                answer,
+                measurement,
+            }
+        })
+        .collect();
+    Ok(Report { parse, parts })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Unsolved;
+    use std::io::Cursor;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<u32>;
+        type PartOne = u32;
+        type PartTwo = Unsolved;
+
+        fn parse(input: &str) -> Result<Vec<u32>> {
+            Ok(input
+                .split(',')
+                .map(str::trim)
+                .filter(|n| !n.is_empty())
+                .map(str::parse)
+                .collect::<Result<_, _>>()?)
+        }
+
+        fn part_one(input: &Vec<u32>) -> u32 {
+            input.iter().sum()
+        }
+
+        fn part_two(_: &Vec<u32>) -> Unsolved {
+            Unsolved
+        }
+    }
+
+    impl Streaming for Sum {
+        type State = u32;
+        const SEPARATOR: u8 = b',';
+
+        fn feed(state: &mut u32, record: &str) -> Result<()> {
+            let number = record.trim_start();
+            let offset = record.len() - number.len();
+            *state += number
+                .parse::<u32>()
+                .map_err(|_| ParseError::at(record, offset, "expected a number"))?;
+            Ok(())
+        }
+
+        fn finish_one(state: &u32) -> u32 {
+            *state
+        }
+
+        fn finish_two(_: &u32) -> Unsolved {
+            Unsolved
+        }
+    }
+
+    #[test]
+    fn streams_records() {
+        let report = solve::<Sum>(&mut Cursor::new("1,2,\r\n3,,4\n"), &Part::ALL).unwrap();
+        assert_eq!(report.answer(Part::One), Some("10"));
+        assert_eq!(report.answer(Part::Two), None);
+    }
+
+    #[test]
+    fn errors_point_into_the_input() {
+        let err = stream::<Sum>(&mut Cursor::new("1,2,\n3,x4")).unwrap_err();
+        let err = err.downcast::<ParseError>().unwrap();
+        assert_eq!(err, ParseError::new(2, 3, "expected a number"));
+    }
+}
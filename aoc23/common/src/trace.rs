@@ -0,0 +1,116 @@
+//! Recording where a solver spends its time, from the `tracing` spans around parsing, each part
+//! and the days' inner routines.
+//!
+//! A chrome trace is JSON for `chrome://tracing` or Perfetto, with the days' counters as events
+//! on the timeline. Folded stacks are one line per stack of spans with the time spent in it, for
+//! `inferno-flamegraph` or `flamegraph.pl`.
+//!
+//! Warnings, like an input line a day can't make sense of, are printed to stderr whether or not
+//! a trace is recorded. Recording one needs the `trace` feature; without it `TraceArgs` takes no
+//! arguments and only sets up the warnings.
+
+#[cfg(feature = "trace")]
+use anyhow::Context;
+use anyhow::Result;
+#[cfg(feature = "trace")]
+use clap::ValueEnum;
+#[cfg(feature = "trace")]
+use std::fs::File;
+#[cfg(feature = "trace")]
+use std::io::BufWriter;
+use std::io::{self, IsTerminal};
+#[cfg(feature = "trace")]
+use std::path::PathBuf;
+use tracing::Subscriber;
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::prelude::*;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::{Layer, Registry};
+
+#[cfg(feature = "trace")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum TraceFormat {
+    Chrome,
+    Folded,
+}
+
+/// Writes out the trace when dropped, which must be after the traced work is done.
+#[must_use = "the trace is written when the guard is dropped"]
+pub enum TraceGuard {
+    #[cfg(feature = "trace")]
+    Chrome(tracing_chrome::FlushGuard),
+    #[cfg(feature = "trace")]
+    Folded(tracing_flame::FlushGuard<BufWriter<File>>),
+    None,
+}
+
+/// The command-line arguments for tracing a run, to `#[command(flatten)]` into a binary's
+/// arguments.
+#[derive(Debug, Clone, clap::Args)]
+#[command(about = None, long_about = None)]
+pub struct TraceArgs {
+    /// Record where the time goes: chrome writes a trace for chrome://tracing or Perfetto,
+    /// folded writes stacks for a flame graph
+    #[cfg(feature = "trace")]
+    #[arg(long, value_enum)]
+    pub trace: Option<TraceFormat>,
+
+    /// Where to write the trace [default: dayN.trace.json or dayN.folded]
+    #[cfg(feature = "trace")]
+    #[arg(long, requires = "trace")]
+    pub trace_output: Option<PathBuf>,
+}
+
+/// A layer writing warnings and errors to `writer`, one line each, in colour if `ansi`.
+pub fn warnings<S, W>(writer: W, ansi: bool) -> impl Layer<S>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    W: for<'w> MakeWriter<'w> + 'static,
+{
+    tracing_subscriber::fmt::layer()
+        .with_writer(writer)
+        .with_ansi(ansi)
+        .without_time()
+        .with_target(false)
+        .with_filter(LevelFilter::WARN)
+}
+
+impl TraceArgs {
+    /// Start printing warnings to stderr, and tracing in the chosen format, if one was chosen,
+    /// for `name`'s default output file. The trace is written when the guard is dropped.
+    #[cfg_attr(not(feature = "trace"), allow(unused_variables))]
+    pub fn start(&self, name: &str) -> Result<TraceGuard> {
+        let registry = Registry::default().with(warnings(io::stderr, io::stderr().is_terminal()));
+        #[cfg(feature = "trace")]
+        if let Some(format) = self.trace {
+            let path = self.trace_output.clone().unwrap_or_else(|| match format {
+                TraceFormat::Chrome => PathBuf::from(format!("{}.trace.json", name)),
+                TraceFormat::Folded => PathBuf::from(format!("{}.folded", name)),
+            });
+            let context = || format!("writing {}", path.display());
+            return match format {
+                TraceFormat::Chrome => {
+                    // Check the file can be written, as the layer panics if not.
+                    File::create(&path).with_context(context)?;
+                    let (layer, guard) = tracing_chrome::ChromeLayerBuilder::new()
+                        .file(&path)
+                        .include_args(true)
+                        .build();
+                    registry.with(layer).try_init()?;
+                    Ok(TraceGuard::Chrome(guard))
+                }
+                TraceFormat::Folded => {
+                    let (layer, guard) =
+                        tracing_flame::FlameLayer::with_file(&path).with_context(context)?;
+                    registry
+                        .with(layer.with_threads_collapsed(true))
+                        .try_init()?;
+                    Ok(TraceGuard::Folded(guard))
+                }
+            };
+        }
+        registry.try_init()?;
+        Ok(TraceGuard::None)
+    }
+}
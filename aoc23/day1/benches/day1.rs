@@ -0,0 +1,84 @@
+use aoc23_1::generate::generate;
+use aoc23_1::{old, value_two, Day1};
+use aoc_common::generate::seeded;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::env;
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+fn bench(c: &mut Criterion) {
+    aoc_common::bench::solution::<Day1>(c, 1);
+}
+
+// Part two's scanners against each other: the automatons, the per-offset search in old.rs, and
+// the C version in main.c, which reads the input itself. The C times include starting the
+// process, so it's also timed on an empty input to show how much of it that is.
+fn scanners(c: &mut Criterion) {
+    let mut inputs = aoc_common::bench::inputs(1);
+    inputs.push(("generated", seeded(generate, 1, 10_000)));
+    let dir = env::temp_dir().join(format!("aoc23-day1-bench-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let c_program = compile_c(&dir);
+
+    for (name, input) in &inputs {
+        let mut group = c.benchmark_group(format!("day1-scanners/{}", name));
+        group.bench_function("automaton", |b| {
+            b.iter(|| black_box(input).lines().filter_map(value_two).sum::<u32>())
+        });
+        group.bench_function("per-offset", |b| {
+            b.iter(|| black_box(input).lines().map(old::value_two).sum::<u32>())
+        });
+        if let Some(program) = &c_program {
+            let path = dir.join(format!("{}.txt", name));
+            fs::write(&path, input).unwrap();
+            let expected = input.lines().filter_map(value_two).sum::<u32>();
+            assert_eq!(run_c(program, &path), format!("Result: {}\n", expected));
+            group.bench_function("C", |b| b.iter(|| run_c(program, &path)));
+        }
+        group.finish();
+    }
+
+    if let Some(program) = &c_program {
+        let empty = dir.join("empty.txt");
+        fs::write(&empty, "").unwrap();
+        c.bench_function("day1-scanners/C on no input", |b| {
+            b.iter(|| run_c(program, &empty))
+        });
+    }
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+/// main.c built with `$CC`, or `cc`, if there is a C compiler.
+fn compile_c(dir: &Path) -> Option<PathBuf> {
+    let source = Path::new(env!("CARGO_MANIFEST_DIR")).join("main.c");
+    let program = dir.join("day1");
+    let compiler = env::var("CC").unwrap_or_else(|_| "cc".to_string());
+    let status = Command::new(&compiler)
+        .args(["-O2", "-o"])
+        .arg(&program)
+        .arg(&source)
+        .status();
+    match status {
+        Ok(status) if status.success() => Some(program),
+        _ => {
+            eprintln!(
+                "Skipping the C scanner: couldn't compile it with {}",
+                compiler
+            );
+            None
+        }
+    }
+}
+
+fn run_c(program: &Path, input: &Path) -> String {
+    let output = Command::new(program)
+        .stdin(File::open(input).unwrap())
+        .stderr(Stdio::inherit())
+        .output()
+        .unwrap();
+    String::from_utf8(output.stdout).unwrap()
+}
+
+criterion_group!(benches, bench, scanners);
+criterion_main!(benches);
@@ -0,0 +1,146 @@
+//! `--explain`: each line of a calibration document with the digits that make its calibration
+//! value marked, followed by every digit read from it, for seeing how lines with overlapping
+//! words like `eightwothree` are read.
+
+use crate::{numeric_tokens, Digits, Token};
+use aoc_common::render::Color;
+use aoc_common::Part;
+use std::fmt::Write;
+
+/// The colours of the first digit, the last, and any part of the line that's in both.
+const FIRST: Color = Color::GREEN;
+const LAST: Color = Color::YELLOW;
+const BOTH: Color = Color::BLUE;
+
+/// How the first and last digits are marked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Markup {
+    /// In colour, for a terminal.
+    Ansi,
+    /// In `[]` for the first and `{}` for the last.
+    Brackets,
+}
+
+/// Each of `lines` as `part` reads it, with the digits spelled out as in `digits` for part two.
+/// Returns the explanation, and a diagnostic for each line with no digit at all.
+pub fn explain(
+    lines: &[String],
+    part: Part,
+    digits: &Digits,
+    markup: Markup,
+) -> (String, Vec<String>) {
+    let mut out = String::new();
+    let mut diagnostics = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        let (tokens, ends) = match part {
+            Part::One => {
+                let tokens = numeric_tokens(line);
+                let ends = tokens.first().cloned().zip(tokens.last().cloned());
+                (tokens, ends)
+            }
+            Part::Two => (digits.tokens(line), digits.first_and_last(line)),
+        };
+        let Some((first, last)) = ends else {
+            writeln!(out, "{:>5}: {}", i + 1, line).unwrap();
+            diagnostics.push(format!("line {}: no digit", i + 1));
+            continue;
+        };
+        let read: Vec<String> = tokens
+            .iter()
+            .map(|token| format!("{} {:?}", &line[token.span.clone()], token.span))
+            .collect();
+        writeln!(
+            out,
+            "{:>5}: {} = {}  ({})",
+            i + 1,
+            mark(line, &first, &last, markup),
+            first.value * 10 + last.value,
+            read.join(", ")
+        )
+        .unwrap();
+    }
+    (out, diagnostics)
+}
+
+/// `line` with `first` and `last` marked.
+fn mark(line: &str, first: &Token, last: &Token, markup: Markup) -> String {
+    let mut out = String::new();
+    match markup {
+        Markup::Ansi => {
+            let mut current = None;
+            for (i, c) in line.char_indices() {
+                let color = match (first.span.contains(&i), last.span.contains(&i)) {
+                    (true, true) => Some(BOTH),
+                    (true, false) => Some(FIRST),
+                    (false, true) => Some(LAST),
+                    (false, false) => None,
+                };
+                if color != current {
+                    match color {
+                        Some(Color { r, g, b }) => {
+                            write!(out, "\x1b[0;1;38;2;{};{};{}m", r, g, b).unwrap()
+                        }
+                        None => out.push_str("\x1b[0m"),
+                    }
+                    current = color;
+                }
+                out.push(c);
+            }
+            if current.is_some() {
+                out.push_str("\x1b[0m");
+            }
+        }
+        Markup::Brackets => {
+            // Where each mark goes, closing before opening where one digit ends and the next
+            // starts.
+            let mut marks = vec![(first.span.start, 1, '['), (first.span.end, 0, ']')];
+            if last != first {
+                marks.extend([(last.span.start, 1, '{'), (last.span.end, 0, '}')]);
+            }
+            marks.sort();
+            let mut written = 0;
+            for (at, _, mark) in marks {
+                out.push_str(&line[written..at]);
+                out.push(mark);
+                written = at;
+            }
+            out.push_str(&line[written..]);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn explains_lines() {
+        let lines = ["eightwothree", "abc", "7pqrstsixteen", "twone"].map(str::to_string);
+        let english: Digits = "en".parse().unwrap();
+        let (two, diagnostics) = explain(&lines, Part::Two, &english, Markup::Brackets);
+        assert_eq!(
+            two,
+            "    1: [eight]wo{three} = 83  (eight 0..5, two 4..7, three 7..12)\n    \
+                 2: abc\n    \
+                 3: [7]pqrst{six}teen = 76  (7 0..1, six 6..9)\n    \
+                 4: [tw{o]ne} = 21  (two 0..3, one 2..5)\n"
+        );
+        assert_eq!(diagnostics, ["line 2: no digit"]);
+
+        let (one, diagnostics) = explain(&lines, Part::One, &english, Markup::Brackets);
+        assert!(one.starts_with("    1: eightwothree\n"));
+        assert!(one.contains("    3: [7]pqrstsixteen = 77  (7 0..1)\n"));
+        assert_eq!(diagnostics.len(), 3);
+    }
+
+    #[test]
+    fn colours_overlaps() {
+        let english: Digits = "en".parse().unwrap();
+        let (first, last) = english.first_and_last("xtwone").unwrap();
+        assert_eq!(
+            mark("xtwone", &first, &last, Markup::Ansi),
+            "x\x1b[0;1;38;2;64;160;43mtw\x1b[0;1;38;2;38;139;210mo\x1b[0;1;38;2;223;180;0mne\x1b[0m"
+        );
+    }
+}
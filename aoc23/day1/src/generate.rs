@@ -0,0 +1,46 @@
+//! Random calibration documents. `size` is the number of lines.
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// Lines of lowercase letters, digits and spelled-out digits, each with at least one real digit
+/// so that both parts have an answer.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let mut document = String::new();
+    for _ in 0..size.max(1) {
+        let mut line = String::new();
+        for _ in 0..rng.gen_range(0..8) {
+            match rng.gen_range(0..4) {
+                0 => line.push(char::from_digit(rng.gen_range(1..=9), 10).unwrap()),
+                1 => line.push_str(WORDS.choose(rng).unwrap()),
+                _ => line.push(rng.gen_range(b'a'..=b'z') as char),
+            }
+        }
+        let at = rng.gen_range(0..=line.len());
+        line.insert(at, char::from_digit(rng.gen_range(1..=9), 10).unwrap());
+        document.push_str(&line);
+        document.push('\n');
+    }
+    document
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day1;
+    use aoc_common::generate::{exercise, exercise_streaming};
+
+    #[test]
+    fn generated_inputs_solve() {
+        exercise::<Day1>(generate, 20, 50);
+    }
+
+    #[test]
+    fn generated_inputs_stream() {
+        exercise_streaming::<Day1>(generate, 20, 50);
+    }
+}
@@ -0,0 +1,431 @@
+#[macro_use]
+extern crate lazy_static;
+
+use aho_corasick::automaton::Automaton;
+use aho_corasick::dfa::DFA;
+use aho_corasick::{AhoCorasick, Anchored, MatchKind, PatternID};
+use anyhow::{anyhow, bail, Error, Result};
+use aoc_common::params::{Param, Params};
+use aoc_common::stream::Streaming;
+use aoc_common::{Part, Solution};
+use num2words::{Lang, Num2Words};
+use std::collections::BTreeMap;
+use std::fmt::{self, Display, Formatter};
+use std::ops::Range;
+use std::str::FromStr;
+use tracing::warn;
+
+pub mod explain;
+#[cfg(any(test, feature = "generate"))]
+pub mod generate;
+#[doc(hidden)]
+pub mod old;
+
+lazy_static! {
+    static ref DIGITS: Digits = "en".parse().unwrap();
+}
+
+/// The codes of the languages `num2words` spells digits in.
+const NUM2WORDS_CODES: [&str; 5] = ["en", "fr", "fr_BE", "fr_CH", "uk"];
+
+/// The digits from one to nine in languages `num2words` doesn't have, by code.
+const WORD_TABLES: [(&str, [&str; 9]); 2] = [
+    (
+        "es",
+        [
+            "uno", "dos", "tres", "cuatro", "cinco", "seis", "siete", "ocho", "nueve",
+        ],
+    ),
+    (
+        "de",
+        [
+            "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+        ],
+    ),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    Numeric,
+    Spelled,
+}
+
+/// A digit read from a line, spelled out or not.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    /// Where it is in the line, in bytes.
+    pub span: Range<usize>,
+    pub kind: TokenKind,
+    pub value: u32,
+}
+
+/// Every digit in `line` as part one reads them, not spelled out.
+pub fn numeric_tokens(line: &str) -> Vec<Token> {
+    line.char_indices()
+        .filter_map(|(start, c)| {
+            Some(Token {
+                span: start..start + c.len_utf8(),
+                kind: TokenKind::Numeric,
+                value: c.to_digit(10)?,
+            })
+        })
+        .collect()
+}
+
+/// The digits, and the digits spelled out in one or more languages, with their values.
+///
+/// Parses from a comma-separated list of `num2words` language codes, like `en,fr`.
+///
+/// Lines are scanned by two automatons built from the words: one finds the first digit reading
+/// forwards, and the other, built from the words reversed, finds the last one reading back
+/// from the end. Each reads a line at most once, and both only ever match whole words, so
+/// never split a letter of several bytes. A third finds every digit, for explaining a line.
+#[derive(Debug, Clone)]
+pub struct Digits {
+    /// The value and kind of each word, by pattern number.
+    words: Vec<(u32, TokenKind)>,
+    forward: AhoCorasick,
+    backward: DFA,
+    overlapping: AhoCorasick,
+}
+
+impl Digits {
+    fn new(words: BTreeMap<String, u32>) -> Result<Digits> {
+        let forward = AhoCorasick::builder()
+            .match_kind(MatchKind::LeftmostFirst)
+            .build(words.keys())?;
+        let backward = DFA::builder().match_kind(MatchKind::Standard).build(
+            words
+                .keys()
+                .map(|word| word.bytes().rev().collect::<Vec<_>>()),
+        )?;
+        let overlapping = AhoCorasick::new(words.keys())?;
+        let words = words
+            .into_iter()
+            .map(|(word, value)| {
+                let kind = match word.bytes().all(|b| b.is_ascii_digit()) {
+                    true => TokenKind::Numeric,
+                    false => TokenKind::Spelled,
+                };
+                (value, kind)
+            })
+            .collect();
+        Ok(Digits {
+            words,
+            forward,
+            backward,
+            overlapping,
+        })
+    }
+
+    fn token(&self, pattern: PatternID, span: Range<usize>) -> Token {
+        let (value, kind) = self.words[pattern];
+        Token { span, kind, value }
+    }
+
+    /// The first and last digits in `line`, spelled out or not, which are the same if there's
+    /// only one.
+    pub fn first_and_last(&self, line: &str) -> Option<(Token, Token)> {
+        let first = self.forward.find(line)?;
+        // Reading back from the end, the first word to end is the one that starts last. The
+        // first digit is found at the latest.
+        let mut state = self
+            .backward
+            .start_state(Anchored::No)
+            .expect("the automaton supports unanchored searches");
+        for start in (first.start()..line.len()).rev() {
+            state = self
+                .backward
+                .next_state(Anchored::No, state, line.as_bytes()[start]);
+            if self.backward.is_special(state) && self.backward.is_match(state) {
+                let last = self.backward.match_pattern(state, 0);
+                let end = start + self.backward.pattern_len(last);
+                return Some((
+                    self.token(first.pattern(), first.range()),
+                    self.token(last, start..end),
+                ));
+            }
+        }
+        unreachable!("reading backwards should find the first digit")
+    }
+
+    /// Every digit in `line`, spelled out or not, including those that overlap, by where they
+    /// start.
+    pub fn tokens(&self, line: &str) -> Vec<Token> {
+        let mut tokens: Vec<Token> = self
+            .overlapping
+            .find_overlapping_iter(line)
+            .map(|m| self.token(m.pattern(), m.range()))
+            .collect();
+        tokens.sort_by_key(|token| (token.span.start, token.span.end));
+        tokens
+    }
+}
+
+impl FromStr for Digits {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Digits> {
+        let mut words: BTreeMap<String, u32> = (1..=9).map(|i| (i.to_string(), i)).collect();
+        for code in s.split(',').map(str::trim) {
+            for (word, i) in spelled(code)?.into_iter().zip(1..) {
+                if let Some(other) = words.insert(word.clone(), i) {
+                    if other != i {
+                        bail!("{} is both {} and {}", word, other, i);
+                    }
+                }
+            }
+        }
+        Digits::new(words)
+    }
+}
+
+/// The digits from one to nine spelled out in the language `code`, from the word tables or
+/// else `num2words`.
+fn spelled(code: &str) -> Result<Vec<String>> {
+    if let Some((_, words)) = WORD_TABLES.iter().find(|(c, _)| *c == code) {
+        return Ok(words.iter().map(|word| word.to_string()).collect());
+    }
+    let lang = || {
+        code.parse::<Lang>().map_err(|_| {
+            anyhow!(
+                "unknown language {}, expected one of {}",
+                code,
+                languages().join(", ")
+            )
+        })
+    };
+    (1..=9)
+        .map(|i| {
+            Ok(Num2Words::new(i)
+                .lang(lang()?)
+                .to_words()
+                .map_err(|_| anyhow!("no word for {} in {}", i, code))?
+                .to_lowercase())
+        })
+        .collect()
+}
+
+/// The languages that can be given for the spelled-out digits, as codes.
+pub fn languages() -> Vec<&'static str> {
+    NUM2WORDS_CODES
+        .into_iter()
+        .filter(|code| code.parse::<Lang>().is_ok())
+        .chain(WORD_TABLES.iter().map(|(code, _)| *code))
+        .collect()
+}
+
+/// The calibration value of `line`, counting digits that are spelled out in English, if it has
+/// any digits.
+pub fn value_two(line: &str) -> Option<u32> {
+    value_spelled(line, &DIGITS)
+}
+
+/// The calibration value of `line`, counting digits that are spelled out as in `digits`, if it
+/// has any digits.
+pub fn value_spelled(line: &str, digits: &Digits) -> Option<u32> {
+    let (first, last) = digits.first_and_last(line)?;
+    Some(first.value * 10 + last.value)
+}
+
+/// The calibration value of `line`: its first and last digits, as a two-digit number, if it
+/// has any.
+pub fn value_one(line: &str) -> Option<u32> {
+    let (first, last) = line
+        .chars()
+        .filter_map(|c| c.to_digit(10))
+        .fold((None, None), |(f, _), x| (f.or(Some(x)), Some(x)));
+    Some(first? * 10 + last?)
+}
+
+/// The sum of the lines' calibration values, which is the answer, and the lines skipped for
+/// having no digit, which count for nothing.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Calibration {
+    pub sum: u32,
+    pub skipped: Vec<String>,
+}
+
+impl Calibration {
+    /// Add `line`'s calibration `value`, or skip it if it has none.
+    fn add(&mut self, value: Option<u32>, line: &str) {
+        match value {
+            Some(value) => self.sum += value,
+            None => self.skipped.push(line.to_string()),
+        }
+    }
+}
+
+impl Display for Calibration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.sum)
+    }
+}
+
+/// The calibration of `lines` for `part`, each line's value read by `value`, warning of each
+/// line skipped.
+fn calibrate(lines: &[String], part: Part, value: impl Fn(&str) -> Option<u32>) -> Calibration {
+    let mut calibration = Calibration::default();
+    for line in lines {
+        let value = value(line);
+        if value.is_none() {
+            warn!(line, "no digit in part {}", part);
+        }
+        calibration.add(value, line);
+    }
+    calibration
+}
+
+fn part_two(lines: &[String], digits: &Digits) -> Calibration {
+    calibrate(lines, Part::Two, |line| value_spelled(line, digits))
+}
+
+fn part_one(lines: &[String]) -> Calibration {
+    calibrate(lines, Part::One, value_one)
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<String>;
+    type PartOne = Calibration;
+    type PartTwo = Calibration;
+
+    const PARAMS: &'static [Param] = &[Param::new::<Digits>(
+        "lang",
+        "en",
+        "Languages of the spelled-out digits, as comma-separated codes like en,fr",
+    )
+    .part(Part::Two)];
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input
+            .split('\n')
+            .filter(|s| !s.is_empty())
+            .map(str::to_owned)
+            .collect())
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        part_two(input, &DIGITS)
+    }
+
+    fn part_two_with(input: &Self::Input, params: &Params) -> Self::PartTwo {
+        part_two(input, &params.get("lang"))
+    }
+}
+
+impl Streaming for Day1 {
+    /// Each part's calibration so far.
+    type State = (Calibration, Calibration);
+
+    fn feed(state: &mut Self::State, line: &str) -> Result<()> {
+        let (one, two) = (value_one(line), value_two(line));
+        // A line with no digit for part two has none for part one either: warn once for both.
+        match (one, two) {
+            (None, None) => warn!(line, "no digit in either part"),
+            (None, Some(_)) => warn!(line, "no digit in part one"),
+            _ => {}
+        }
+        state.0.add(one, line);
+        state.1.add(two, line);
+        Ok(())
+    }
+
+    fn finish_one(state: &Self::State) -> Self::PartOne {
+        state.0.clone()
+    }
+
+    fn finish_two(state: &Self::State) -> Self::PartTwo {
+        state.1.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::stream::stream;
+    use aoc_common::trace::warnings;
+    use std::io;
+    use std::sync::{Arc, Mutex};
+    use tracing_subscriber::prelude::*;
+    use tracing_subscriber::Registry;
+
+    #[test]
+    fn other_languages() {
+        let french: Digits = "fr".parse().unwrap();
+        assert_eq!(value_spelled("xdeuxzéro5troisé", &french), Some(23));
+        assert_eq!(value_spelled("ééfive7", &french), Some(77));
+        let both: Digits = "en, fr".parse().unwrap();
+        assert_eq!(value_spelled("ééfive7huit", &both), Some(58));
+        assert!("en,xx".parse::<Digits>().is_err());
+
+        let spanish_german: Digits = "es,de".parse().unwrap();
+        assert_eq!(value_spelled("xdosfünfeins", &spanish_german), Some(21));
+        assert_eq!(value_spelled("nueveñ3", &spanish_german), Some(93));
+    }
+
+    /// The warnings written while running `f`, and what it returns.
+    fn capture_warnings<T>(f: impl FnOnce() -> T) -> (T, String) {
+        #[derive(Clone, Default)]
+        struct Captured(Arc<Mutex<Vec<u8>>>);
+
+        impl io::Write for Captured {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.lock().unwrap().write(buf)
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let captured = Captured::default();
+        let writer = captured.clone();
+        let subscriber = Registry::default().with(warnings(move || writer.clone(), false));
+        let result = tracing::subscriber::with_default(subscriber, f);
+        let output = String::from_utf8(captured.0.lock().unwrap().clone()).unwrap();
+        (result, output)
+    }
+
+    #[test]
+    fn skips_lines_with_no_digit() {
+        let lines = ["abc".to_string(), "1abc2".to_string(), "two".to_string()];
+        let (calibration, output) = capture_warnings(|| part_one(&lines));
+        assert_eq!(calibration.sum, 12);
+        assert_eq!(calibration.skipped, ["abc", "two"]);
+        assert_eq!(calibration.to_string(), "12");
+        assert_eq!(
+            output.matches("no digit in part one").count(),
+            2,
+            "{}",
+            output
+        );
+        assert!(!output.contains("1abc2"), "{}", output);
+
+        let (calibration, _) = capture_warnings(|| part_two(&lines, &DIGITS));
+        assert_eq!(
+            (calibration.sum, calibration.skipped),
+            (34, vec!["abc".to_string()])
+        );
+    }
+
+    #[test]
+    fn streaming_warns_once_a_line() {
+        let input = "abc\n1abc2\ntwo\n";
+        let (state, output) = capture_warnings(|| stream::<Day1>(&mut input.as_bytes()).unwrap());
+        assert_eq!(state.0.skipped, ["abc", "two"]);
+        assert_eq!(state.1.skipped, ["abc"]);
+        assert_eq!(output.lines().count(), 2, "{}", output);
+        assert!(output.contains("no digit in either part"), "{}", output);
+    }
+
+    #[test]
+    fn letters_of_several_bytes() {
+        assert_eq!(value_two("ñoneü2€nine"), Some(19));
+    }
+}
@@ -1,56 +1,90 @@
-#[macro_use]
-extern crate lazy_static;
-
-use num2words::{Lang, Num2Words};
-use std::collections::HashMap;
-use std::fs;
-
-lazy_static! {
-    static ref DIGITS: HashMap<String, u32> = {
-        let mut map = HashMap::new();
-        for i in 1..=9 {
-            map.insert(i.to_string(), i);
-            map.insert(Num2Words::new(i).lang(Lang::English).to_words().unwrap(), i);
-        }
-        map
-    };
-}
+use anyhow::Result;
+use aoc23_1::explain::{explain, Markup};
+use aoc23_1::{Day1, Digits};
+use aoc_common::input::InputArgs;
+use aoc_common::params::{self, Override, ParamArgs, Params};
+use aoc_common::stream;
+use aoc_common::trace::TraceArgs;
+use aoc_common::{Part, Solution};
+use clap::Parser;
+use std::io::{self, IsTerminal};
 
-fn value_two(line: &str) -> u32 {
-    let (first, last) = (0..line.len())
-        .filter_map(|start| {
-            DIGITS
-                .iter()
-                .flat_map(|(s, d)| line[start..].starts_with(s).then_some(d))
-                .next()
-        })
-        .copied()
-        .fold((None, None), |(f, _), x| (f.or(Some(x)), Some(x)));
-    first.unwrap() * 10 + last.unwrap()
-}
+#[derive(Debug, Parser)]
+#[command(about)]
+struct Args {
+    #[command(flatten)]
+    input: InputArgs,
 
-fn value_one(line: &str) -> u32 {
-    let (first, last) = line
-        .chars()
-        .filter_map(|c| c.to_digit(10))
-        .fold((None, None), |(f, _), x| (f.or(Some(x)), Some(x)));
-    first.unwrap() * 10 + last.unwrap()
-}
+    #[command(flatten)]
+    params: ParamArgs,
 
-fn part_two(lines: &[&str]) -> u32 {
-    lines.iter().map(|line| value_two(line)).sum()
-}
+    #[command(flatten)]
+    trace: TraceArgs,
+
+    /// Solve the input as it's read rather than reading it all first, for very large inputs
+    #[arg(long, conflicts_with = "params")]
+    stream: bool,
+
+    /// Languages of the spelled-out digits in part two, as comma-separated codes like en,fr:
+    /// any of en, fr, fr_BE, fr_CH and uk that num2words has, and es and de. The same as
+    /// --param lang=LANGS
+    #[arg(long, value_name = "LANGS", conflicts_with = "stream")]
+    lang: Option<String>,
 
-fn part_one(lines: &[&str]) -> u32 {
-    lines.iter().map(|line| value_one(line)).sum()
+    /// Instead of solving, print each line with the digits that make its calibration value
+    /// marked and every digit read from it, and report any lines with no digit
+    #[arg(long, conflicts_with = "stream")]
+    explain: bool,
 }
 
-fn main() {
-    let input = fs::read_to_string("inputs/day1.txt").unwrap();
-    let lines = input
-        .split('\n')
-        .filter(|s| !s.is_empty())
-        .collect::<Vec<_>>();
-    println!("Part one: {}", part_one(&lines));
-    println!("Part two: {}", part_two(&lines));
+fn main() -> Result<()> {
+    let mut args = params::parse_args::<Args, Day1>();
+    let _trace = args.trace.start("day1")?;
+
+    if args.stream {
+        let report = stream::solve::<Day1>(&mut args.input.open(1)?, &args.params.parts())?;
+        for part in &report.parts {
+            if let Some(answer) = &part.answer {
+                println!("Part {}: {}", part.part, answer);
+            }
+        }
+        return Ok(());
+    }
+
+    if let Some(lang) = args.lang.take() {
+        // Check the languages here, for a better error than an invalid parameter.
+        lang.parse::<Digits>()?;
+        args.params.params.push(Override {
+            part: None,
+            name: "lang".to_string(),
+            value: lang,
+        });
+    }
+
+    if args.explain {
+        let lines = Day1::parse(&args.input.read(1)?.replace("\r\n", "\n"))?;
+        let digits: Digits =
+            Params::resolve(Day1::PARAMS, Part::Two, &args.params.params)?.get("lang");
+        let markup = match io::stdout().is_terminal() {
+            true => Markup::Ansi,
+            false => Markup::Brackets,
+        };
+        for part in args.params.parts() {
+            let (explanation, diagnostics) = explain(&lines, part, &digits, markup);
+            println!("Part {}:", part);
+            print!("{}", explanation);
+            for diagnostic in diagnostics {
+                eprintln!("Part {}, {}", part, diagnostic);
+            }
+        }
+        return Ok(());
+    }
+
+    let input = args.input.map(1)?;
+    for part in args.params.parts() {
+        if let Some(answer) = aoc_common::run::<Day1>(&input, part, &args.params.params)? {
+            println!("Part {}: {}", part, answer);
+        }
+    }
+    Ok(())
 }
@@ -0,0 +1,48 @@
+//! The first scanner for part two, which tries every digit, spelled out or not, at the start
+//! of every letter. Kept as a baseline for the benchmarks.
+
+use num2words::{Lang, Num2Words};
+use std::collections::HashMap;
+
+lazy_static! {
+    static ref DIGITS: HashMap<String, u32> = {
+        let mut map = HashMap::new();
+        for i in 1..=9 {
+            map.insert(i.to_string(), i);
+            map.insert(Num2Words::new(i).lang(Lang::English).to_words().unwrap(), i);
+        }
+        map
+    };
+}
+
+/// The calibration value of `line`, counting digits that are spelled out in English.
+pub fn value_two(line: &str) -> u32 {
+    let (first, last) = line
+        .char_indices()
+        .filter_map(|(start, _)| {
+            DIGITS
+                .iter()
+                .flat_map(|(s, d)| line[start..].starts_with(s).then_some(d))
+                .next()
+        })
+        .copied()
+        .fold((None, None), |(f, _), x| (f.or(Some(x)), Some(x)));
+    first.unwrap() * 10 + last.unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::generate;
+    use aoc_common::generate::seeded;
+
+    #[test]
+    fn agrees_with_the_automaton() {
+        for line in ["twone", "eighthree", "7oneight", "sevenine2", "ninine", "1"] {
+            assert_eq!(crate::value_two(line), Some(value_two(line)), "{}", line);
+        }
+        for line in seeded(generate, 1, 500).lines() {
+            assert_eq!(crate::value_two(line), Some(value_two(line)), "{}", line);
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use aoc23_10::Day10;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn bench(c: &mut Criterion) {
+    aoc_common::bench::solution::<Day10>(c, 10);
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
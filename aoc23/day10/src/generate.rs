@@ -0,0 +1,92 @@
+//! Random pipe mazes. `size` is roughly the width and height of the maze.
+
+use aoc_common::generate::random_loop;
+use aoc_common::{Direction, Point};
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::cmp::Ordering;
+
+/// A maze with one loop through the start, and junk pipes everywhere else except where they
+/// would connect to the start.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let blocks = (size / 4).max(1);
+    let (width, height) = (4 * blocks, 4 * blocks);
+    let mut maze: Vec<Vec<char>> = (0..height)
+        .map(|_| {
+            (0..width)
+                .map(|_| *b".|-LJ7F".choose(rng).unwrap() as char)
+                .collect()
+        })
+        .collect();
+
+    // A loop of 2 x 2 blocks has nothing inside it, so spread it out to twice the size.
+    let small = random_loop(rng, blocks, blocks);
+    let cells: Vec<_> = small
+        .iter()
+        .zip(small.iter().cycle().skip(1))
+        .flat_map(|(&a, &b)| [a * 2, a + b])
+        .collect();
+    for (i, &cell) in cells.iter().enumerate() {
+        let previous = cells[(i + cells.len() - 1) % cells.len()];
+        let next = cells[(i + 1) % cells.len()];
+        maze[cell.y][cell.x] = pipe(towards(cell, previous), towards(cell, next));
+    }
+
+    let start = *cells.choose(rng).unwrap();
+    maze[start.y][start.x] = 'S';
+    for dir in Direction::ALL {
+        if let Some(n) = start.step(dir, width, height) {
+            if !cells.contains(&n) && pipe_exits(maze[n.y][n.x]).contains(&dir.opposite()) {
+                maze[n.y][n.x] = '.';
+            }
+        }
+    }
+
+    maze.iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
+
+fn towards(from: Point<usize>, to: Point<usize>) -> Direction {
+    match (to.x.cmp(&from.x), to.y.cmp(&from.y)) {
+        (Ordering::Less, _) => Direction::Left,
+        (Ordering::Greater, _) => Direction::Right,
+        (_, Ordering::Less) => Direction::Up,
+        _ => Direction::Down,
+    }
+}
+
+fn pipe(a: Direction, b: Direction) -> char {
+    *b"|-LJ7F"
+        .iter()
+        .find(|&&pipe| {
+            let exits = pipe_exits(pipe as char);
+            exits.contains(&a) && exits.contains(&b)
+        })
+        .expect("every pair of directions has a pipe") as char
+}
+
+fn pipe_exits(pipe: char) -> Vec<Direction> {
+    use Direction::*;
+    match pipe {
+        '|' => vec![Up, Down],
+        '-' => vec![Left, Right],
+        'L' => vec![Up, Right],
+        'J' => vec![Up, Left],
+        '7' => vec![Down, Left],
+        'F' => vec![Down, Right],
+        _ => vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day10;
+    use aoc_common::generate::exercise;
+
+    #[test]
+    fn generated_inputs_solve() {
+        exercise::<Day10>(generate, 20, 50);
+    }
+}
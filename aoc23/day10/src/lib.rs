@@ -0,0 +1,147 @@
+use anyhow::{anyhow, Result};
+use aoc_common::parse::{BadChar, ParseError};
+use aoc_common::{Point, Solution};
+use derive_more::Constructor;
+use itertools::Itertools;
+use std::collections::HashMap;
+
+#[cfg(any(test, feature = "generate"))]
+pub mod generate;
+pub mod reference;
+pub mod render;
+
+pub type Location = Point<i32>;
+
+/// The pipes, as the two tiles each one connects.
+#[derive(Constructor)]
+pub struct Graph {
+    pub graph: HashMap<Location, [Location; 2]>,
+    pub start: Location,
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Location>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(find_loop(&parse_input(input)?))
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        part_two(input)
+    }
+}
+
+fn part_one(cage: &[Location]) -> u32 {
+    cage.len() as u32 / 2
+}
+
+fn part_two(cage: &[Location]) -> u32 {
+    // Pick's theorem: area = interior + (boundary / 2) - 1
+    // interior = area - (boundary / 2) + 1
+    // which is zero for a loop with nothing inside, so add before subtracting.
+    let area = polygon_area(cage);
+    let peri = cage.len() as u32;
+    area + 1 - (peri / 2)
+}
+
+/// The tiles of the loop through the start, in order, starting and ending at the start.
+pub fn find_loop(input: &Graph) -> Vec<Location> {
+    let mut cage = Vec::new();
+
+    let start = input.start;
+    let mut cur = input.graph.get(&start).unwrap()[0];
+    let mut last = start;
+
+    cage.push(start);
+
+    while cur != start {
+        cage.push(cur);
+        let next = input
+            .graph
+            .get(&cur)
+            .unwrap()
+            .iter()
+            .find(|n| **n != last)
+            .unwrap();
+        last = cur;
+        cur = *next;
+    }
+
+    cage.push(cur);
+
+    cage
+}
+
+/// The area enclosed by the polygon with these vertices, by the shoelace formula.
+pub fn polygon_area(points: &[Location]) -> u32 {
+    points
+        .iter()
+        .tuple_windows()
+        .map(|(p1, p2)| p1.x * p2.y - p2.x * p1.y)
+        .sum::<i32>()
+        .unsigned_abs()
+        / 2
+}
+
+/// Parse the pipes. [`Day10`]'s input is the loop found in them.
+pub fn parse_input(input: &str) -> Result<Graph> {
+    let mut graph: HashMap<Location, [Location; 2]> = HashMap::new();
+    let mut start: Option<Location> = None;
+    for (row, line) in input.lines().enumerate() {
+        for (col, symbol) in line.trim().chars().enumerate() {
+            let conn = connections(row as i32, col as i32, symbol)
+                .map_err(|e| ParseError::new(row + 1, col + 1, e.to_string()))?;
+            if let Some(conn) = conn {
+                graph.insert(Point::new(col as i32, row as i32), conn);
+            }
+            if symbol == 'S' {
+                start = Some(Point::new(col as i32, row as i32));
+            }
+        }
+    }
+
+    let start = start.ok_or_else(|| anyhow!("no start tile 'S'"))?;
+    let connected_to_start: [Location; 2] = graph
+        .iter()
+        .filter(|(_, conns)| conns[0] == start || conns[1] == start)
+        .map(|(loc, _)| *loc)
+        .collect::<Vec<_>>()
+        .try_into()
+        .map_err(|conns: Vec<_>| {
+            ParseError::new(
+                start.y as usize + 1,
+                start.x as usize + 1,
+                format!("expected 2 pipes connected to start, found {}", conns.len()),
+            )
+        })?;
+    graph.insert(start, connected_to_start);
+
+    Ok(Graph { graph, start })
+}
+
+fn connections(row: i32, col: i32, symbol: char) -> Result<Option<[Location; 2]>, BadChar> {
+    Ok(match symbol {
+        '|' => Some([Point::new(col, row + 1), Point::new(col, row - 1)]),
+        '-' => Some([Point::new(col + 1, row), Point::new(col - 1, row)]),
+        'L' => Some([Point::new(col, row - 1), Point::new(col + 1, row)]),
+        'J' => Some([Point::new(col, row - 1), Point::new(col - 1, row)]),
+        '7' => Some([Point::new(col, row + 1), Point::new(col - 1, row)]),
+        'F' => Some([Point::new(col, row + 1), Point::new(col + 1, row)]),
+        '.' => None,
+        'S' => None,
+        found => {
+            return Err(BadChar {
+                found,
+                expected: "|-LJ7F.S",
+            })
+        }
+    })
+}
@@ -0,0 +1,74 @@
+//! A naive reference solver, to check the shoelace formula and Pick's theorem against.
+
+use crate::Location;
+use aoc_common::Point;
+use std::collections::HashSet;
+
+/// The number of tiles enclosed by the loop, by casting a ray left from each tile and counting
+/// the loop's vertical steps that it crosses.
+pub fn enclosed_tiles(cage: &[Location]) -> u32 {
+    enclosed(cage).len() as u32
+}
+
+/// The tiles enclosed by the loop, found as for [`enclosed_tiles`].
+pub fn enclosed(cage: &[Location]) -> Vec<Location> {
+    let on_loop: HashSet<Location> = cage.iter().copied().collect();
+    // A step between a tile and the one above it, keyed by the lower tile.
+    let risers: HashSet<Location> = cage
+        .windows(2)
+        .filter(|step| step[0].x == step[1].x)
+        .map(|step| Point::new(step[0].x, step[0].y.max(step[1].y)))
+        .collect();
+    let (min_x, max_x) = (
+        cage.iter().map(|p| p.x).min().unwrap(),
+        cage.iter().map(|p| p.x).max().unwrap(),
+    );
+    let (min_y, max_y) = (
+        cage.iter().map(|p| p.y).min().unwrap(),
+        cage.iter().map(|p| p.y).max().unwrap(),
+    );
+
+    let mut enclosed = Vec::new();
+    for y in min_y..=max_y {
+        for x in min_x..=max_x {
+            let tile = Point::new(x, y);
+            let crossings = (min_x..x)
+                .filter(|&left| risers.contains(&Point::new(left, y)))
+                .count();
+            if !on_loop.contains(&tile) && crossings % 2 == 1 {
+                enclosed.push(tile);
+            }
+        }
+    }
+    enclosed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::generate;
+    use crate::Day10;
+    use aoc_common::generate::{random_loop, seeded};
+    use aoc_common::Solution;
+    use proptest::prelude::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    proptest! {
+        #[test]
+        fn agrees_with_part_two(seed: u64, size in 1..40usize) {
+            let cage = Day10::parse(&seeded(generate, seed, size)).unwrap();
+            prop_assert_eq!(crate::part_two(&cage), enclosed_tiles(&cage));
+        }
+
+        #[test]
+        fn agrees_with_part_two_on_empty_loops(seed: u64, width in 1..8usize, height in 1..8usize) {
+            let mut cage: Vec<Location> = random_loop(&mut StdRng::seed_from_u64(seed), width, height)
+                .into_iter()
+                .map(|p| Point::new(p.x as i32, p.y as i32))
+                .collect();
+            cage.push(cage[0]);
+            prop_assert_eq!(crate::part_two(&cage), enclosed_tiles(&cage));
+        }
+    }
+}
@@ -0,0 +1,32 @@
+//! Drawing the maze.
+
+use crate::{find_loop, parse_input, reference, Location};
+use anyhow::Result;
+use aoc_common::grid::try_parse_grid;
+use aoc_common::render::{Canvas, Color, Style};
+use aoc_common::Point;
+use std::convert::Infallible;
+
+/// The maze in box-drawing characters, with the loop as a path and the tiles it encloses
+/// highlighted.
+pub fn render(input: &str) -> Result<Canvas> {
+    let cage = find_loop(&parse_input(input)?);
+    let tiles = try_parse_grid(input, Ok::<char, Infallible>)?;
+    let mut canvas = Canvas::new(&tiles, |&tile| {
+        let glyph = match tile {
+            '|' => '│',
+            '-' => '─',
+            'L' => '└',
+            'J' => '┘',
+            '7' => '┐',
+            'F' => '┌',
+            other => other,
+        };
+        Style::new(glyph, Color::GREY)
+    });
+    let cell = |p: &Location| Point::new(p.x as usize, p.y as usize);
+    canvas
+        .highlight(reference::enclosed(&cage).iter().map(cell), Color::GREEN)
+        .path(cage.iter().map(cell).collect(), Color::YELLOW);
+    Ok(canvas)
+}
@@ -0,0 +1,9 @@
+use aoc23_11::Day11;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn bench(c: &mut Criterion) {
+    aoc_common::bench::solution::<Day11>(c, 11);
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
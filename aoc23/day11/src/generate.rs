@@ -0,0 +1,33 @@
+//! Random images of the sky. `size` is the width and height of the image.
+
+use rand::Rng;
+
+/// A square image with galaxies scattered at a random density, and at least one of them.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let size = size.max(1);
+    let density = rng.gen_range(0.02..0.2);
+    let mut image: Vec<Vec<u8>> = (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| if rng.gen_bool(density) { b'#' } else { b'.' })
+                .collect()
+        })
+        .collect();
+    image[rng.gen_range(0..size)][rng.gen_range(0..size)] = b'#';
+    image
+        .into_iter()
+        .map(|row| String::from_utf8(row).unwrap() + "\n")
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day11;
+    use aoc_common::generate::exercise;
+
+    #[test]
+    fn generated_inputs_solve() {
+        exercise::<Day11>(generate, 20, 50);
+    }
+}
@@ -0,0 +1,113 @@
+use anyhow::Result;
+use aoc_common::params::{Param, Params};
+use aoc_common::{Part, Point, Solution};
+use itertools::Itertools;
+use std::cmp::{max, min};
+use std::collections::HashSet;
+
+#[cfg(any(test, feature = "generate"))]
+pub mod generate;
+pub mod reference;
+
+pub type Galaxy = Point<usize>;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = HashSet<Galaxy>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    const PARAMS: &'static [Param] = &[
+        Param::new::<usize>("expansion", "2", "Rows or columns each empty one becomes")
+            .part(Part::One)
+            .at_least(1),
+        Param::new::<usize>(
+            "expansion",
+            "1000000",
+            "Rows or columns each empty one becomes",
+        )
+        .part(Part::Two)
+        .at_least(1),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input))
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        Self::part_one_with(input, &Params::defaults(Self::PARAMS, Part::One))
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        Self::part_two_with(input, &Params::defaults(Self::PARAMS, Part::Two))
+    }
+
+    fn part_one_with(input: &Self::Input, params: &Params) -> Self::PartOne {
+        expanded_galaxy_pair_distances(input, params.get("expansion"))
+    }
+
+    fn part_two_with(input: &Self::Input, params: &Params) -> Self::PartTwo {
+        expanded_galaxy_pair_distances(input, params.get("expansion"))
+    }
+}
+
+fn parse_input(input: &str) -> HashSet<Galaxy> {
+    let mut galaxies = HashSet::new();
+    for (row, line) in input
+        .split('\n')
+        .filter(|line| !line.is_empty())
+        .enumerate()
+    {
+        for (col, symbol) in line.trim().chars().enumerate() {
+            if symbol == '#' {
+                galaxies.insert(Point::new(col, row));
+            }
+        }
+    }
+    galaxies
+}
+
+/// The sum of the distances between every pair of galaxies, once each empty row and column is
+/// replaced with `expansion_factor` of them.
+pub fn expanded_galaxy_pair_distances(input: &HashSet<Galaxy>, expansion_factor: usize) -> usize {
+    let max_x = input.iter().map(|p| p.x).max().unwrap();
+    let max_y = input.iter().map(|p| p.y).max().unwrap();
+
+    let mut doubled_x = (0..=max_x).collect::<HashSet<_>>();
+    let mut doubled_y = (0..=max_y).collect::<HashSet<_>>();
+    for g in input {
+        doubled_x.remove(&g.x);
+        doubled_y.remove(&g.y);
+    }
+
+    let mut sum = 0;
+    for (a, b) in input.iter().tuple_combinations() {
+        let dist = expanded_taxicab_distance(a, b, &doubled_x, &doubled_y, expansion_factor);
+        sum += dist;
+    }
+    sum
+}
+
+fn expanded_taxicab_distance(
+    a: &Galaxy,
+    b: &Galaxy,
+    doubled_x: &HashSet<usize>,
+    doubled_y: &HashSet<usize>,
+    expansion_factor: usize,
+) -> usize {
+    let max_x = max(a.x, b.x);
+    let min_x = min(a.x, b.x);
+    let max_y = max(a.y, b.y);
+    let min_y = min(a.y, b.y);
+    let expand_x = doubled_x
+        .iter()
+        .filter(|x| min_x <= **x && **x <= max_x)
+        .count();
+    let expand_y = doubled_y
+        .iter()
+        .filter(|y| min_y <= **y && **y <= max_y)
+        .count();
+
+    (max_x - min_x) + (max_y - min_y) + (expand_x + expand_y) * (expansion_factor - 1)
+}
@@ -0,0 +1,55 @@
+//! A naive reference solver, to check the distance formula against.
+
+use crate::Galaxy;
+use itertools::Itertools;
+use std::collections::HashSet;
+
+/// The sum of the distances between every pair of galaxies, by working out where each galaxy
+/// ends up once the image is expanded, one row and column at a time.
+pub fn expanded_galaxy_pair_distances(input: &HashSet<Galaxy>, expansion_factor: usize) -> usize {
+    // Where each row or column starts after expansion, along one axis.
+    let expand = |coords: Vec<usize>| {
+        let mut start = 0;
+        (0..=coords.iter().copied().max().unwrap())
+            .map(|i| {
+                let here = start;
+                start += if coords.contains(&i) {
+                    1
+                } else {
+                    expansion_factor
+                };
+                here
+            })
+            .collect::<Vec<_>>()
+    };
+    let xs = expand(input.iter().map(|g| g.x).collect());
+    let ys = expand(input.iter().map(|g| g.y).collect());
+    input
+        .iter()
+        .tuple_combinations()
+        .map(|(a, b)| xs[a.x].abs_diff(xs[b.x]) + ys[a.y].abs_diff(ys[b.y]))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Point;
+    use proptest::collection::hash_set;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn agrees_with_expanded_galaxy_pair_distances(
+            galaxies in hash_set((0..20usize, 0..20usize), 1..30),
+            expansion_factor in 1..1_000_000usize,
+        ) {
+            let galaxies: HashSet<Galaxy> =
+                galaxies.into_iter().map(|(x, y)| Point::new(x, y)).collect();
+            prop_assert_eq!(
+                crate::expanded_galaxy_pair_distances(&galaxies, expansion_factor),
+                expanded_galaxy_pair_distances(&galaxies, expansion_factor)
+            );
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use aoc23_12::Day12;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn bench(c: &mut Criterion) {
+    aoc_common::bench::solution::<Day12>(c, 12);
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
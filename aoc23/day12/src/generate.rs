@@ -0,0 +1,54 @@
+//! Random spring condition records. `size` is the number of rows.
+
+use rand::seq::index;
+use rand::Rng;
+
+/// Rows of up to 20 springs, at least one of them damaged, with their groups taken from the row
+/// before up to eight springs are hidden behind `?`. Some rows have no `?` at all.
+///
+/// Part two's unfolded rows have up to five times as many unknowns, so allowing more than
+/// eight would risk overflowing the count of arrangements.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let mut records = String::new();
+    for _ in 0..size.max(1) {
+        let length = rng.gen_range(1..=20);
+        let mut row: Vec<u8> = (0..length)
+            .map(|_| if rng.gen() { b'#' } else { b'.' })
+            .collect();
+        row[rng.gen_range(0..length)] = b'#';
+
+        let groups: Vec<String> = row
+            .split(|&c| c == b'.')
+            .filter(|group| !group.is_empty())
+            .map(|group| group.len().to_string())
+            .collect();
+
+        let unknowns = rng.gen_range(0..=length.min(8));
+        for i in index::sample(rng, length, unknowns) {
+            row[i] = b'?';
+        }
+        records.push_str(&format!(
+            "{} {}\n",
+            String::from_utf8(row).unwrap(),
+            groups.join(",")
+        ));
+    }
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day12;
+    use aoc_common::generate::{exercise, exercise_streaming};
+
+    #[test]
+    fn generated_inputs_solve() {
+        exercise::<Day12>(generate, 20, 50);
+    }
+
+    #[test]
+    fn generated_inputs_stream() {
+        exercise_streaming::<Day12>(generate, 20, 50);
+    }
+}
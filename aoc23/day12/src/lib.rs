@@ -0,0 +1,262 @@
+use anyhow::Result;
+use aoc_common::parse::{lines, one_of, parse_all, tag, IResult};
+use aoc_common::stream::Streaming;
+use aoc_common::Solution;
+use itertools::Itertools;
+use nom::character::complete::{space1, u64 as nom_u64};
+use nom::combinator::map;
+use nom::multi::{many1, separated_list1};
+use nom::sequence::separated_pair;
+use rayon::prelude::*;
+use std::cell::Cell;
+use std::cmp::min;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::iter::repeat_n;
+use std::ops::Add;
+use tracing::debug;
+
+#[cfg(any(test, feature = "generate"))]
+pub mod generate;
+pub mod reference;
+
+#[derive(Debug, PartialEq, Copy, Clone, Eq, Hash)]
+pub enum SpringState {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+impl Display for SpringState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let c = match self {
+            SpringState::Operational => '.',
+            SpringState::Damaged => '#',
+            SpringState::Unknown => '?',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+#[derive(Debug)]
+pub struct Spring {
+    pub states: Vec<SpringState>,
+    pub groups: Vec<usize>,
+}
+
+#[derive(Debug)]
+pub struct Input {
+    pub springs: Vec<Spring>,
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Input;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_all(input, parse_input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        part_two(input)
+    }
+}
+
+impl Streaming for Day12 {
+    /// The sums of each part's arrangements so far. Each row is counted as it's read, so
+    /// unlike the whole-input parts, the rows aren't counted in parallel.
+    type State = (usize, usize);
+
+    fn feed(state: &mut Self::State, line: &str) -> Result<()> {
+        for spring in parse_all(line, parse_input)?.springs {
+            state.0 += arrangements(&spring).0;
+            state.1 += arrangements(&unfold(&spring)).0;
+        }
+        Ok(())
+    }
+
+    fn finish_one(state: &Self::State) -> Self::PartOne {
+        state.0
+    }
+
+    fn finish_two(state: &Self::State) -> Self::PartTwo {
+        state.1
+    }
+}
+
+fn part_one(input: &Input) -> usize {
+    total(input.springs.par_iter().map(arrangements))
+}
+
+fn part_two(input: &Input) -> usize {
+    total(
+        input
+            .springs
+            .par_iter()
+            .map(|spring| arrangements(&unfold(spring))),
+    )
+}
+
+/// The sum of the springs' arrangements, tracing how well their caches did.
+fn total(counts: impl ParallelIterator<Item = (usize, CacheStats)>) -> usize {
+    let (total, stats) = counts.reduce(Default::default, |a, b| (a.0 + b.0, a.1 + b.1));
+    debug!(stats.hits, stats.misses, "combinations cache");
+    total
+}
+
+/// How often the cache of [`combinations`] already had the answer.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct CacheStats {
+    hits: usize,
+    misses: usize,
+}
+
+impl Add for CacheStats {
+    type Output = CacheStats;
+
+    fn add(self, other: CacheStats) -> CacheStats {
+        CacheStats {
+            hits: self.hits + other.hits,
+            misses: self.misses + other.misses,
+        }
+    }
+}
+
+thread_local! {
+    /// The lookups in [`combinations_cached`] on this thread so far.
+    static LOOKUPS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// The number of arrangements of `spring`, and how its cache did.
+fn arrangements(spring: &Spring) -> (usize, CacheStats) {
+    let before = LOOKUPS.get();
+    let mut cache = HashMap::new();
+    let count = combinations(&spring.states, &spring.groups, &mut cache);
+    // Every miss adds an entry to the cache.
+    let misses = cache.len();
+    let hits = LOOKUPS.get() - before - misses;
+    (count, CacheStats { hits, misses })
+}
+
+/// The number of ways to fill in the `Unknown` states so that the damaged springs form `groups`.
+///
+/// `cache` is keyed on how much of `states` and `groups` is left, so it must start empty for
+/// each spring.
+pub fn combinations(
+    states: &[SpringState],
+    groups: &[usize],
+    cache: &mut HashMap<(usize, usize), usize>,
+) -> usize {
+    if groups.is_empty() {
+        return if states.contains(&SpringState::Damaged) {
+            0
+        } else {
+            1
+        };
+    }
+
+    // groups is not empty, so this is not a valid state
+    if states.is_empty() {
+        return 0;
+    }
+
+    // try to locate the first group starting at starting position X
+    // all positions < X are SpringState::Operational
+    // positions [X, X + group_size) are SpringState::Damaged
+    // unless X + group_size is off the end of the slice, it must be SpringState::Operational
+    let mut total_combinations = 0;
+
+    let group_size = groups[0];
+    let remaining_groups = &groups[1..];
+
+    if states.len() < group_size {
+        return 0;
+    }
+
+    for tent_start_pos in 0..=(states.len() - group_size) {
+        // If this is true, we cannot consider all of the springs in the proposed range
+        // to be Damaged, because at least one of them is Operational.
+        let damaged_range_invalid =
+            states[tent_start_pos..tent_start_pos + group_size].contains(&SpringState::Operational);
+        // If this is true, then the proposed range cannot be this group of Damaged springs,
+        // because the subsequent spring is also Damaged (so there is not the necessary terminator).
+        let operational_terminator_impossible = (states.len() > tent_start_pos + group_size)
+            && (states[tent_start_pos + group_size] == SpringState::Damaged);
+        // If this is true, this is the last possible tentative placement, since after this point,
+        // one of the springs to the left of the tentative placement will be damaged.
+        let last_possible_placement = states[tent_start_pos] == SpringState::Damaged;
+
+        if !damaged_range_invalid && !operational_terminator_impossible {
+            // Possible range placement
+            let remaining_state = &states[min(states.len(), tent_start_pos + group_size + 1)..];
+            total_combinations += combinations_cached(remaining_state, remaining_groups, cache);
+        }
+
+        if last_possible_placement {
+            return total_combinations;
+        }
+    }
+
+    total_combinations
+}
+
+fn combinations_cached(
+    states: &[SpringState],
+    groups: &[usize],
+    cache: &mut HashMap<(usize, usize), usize>,
+) -> usize {
+    LOOKUPS.set(LOOKUPS.get() + 1);
+    let key = (states.len(), groups.len());
+    if let Some(result) = cache.get(&key) {
+        *result
+    } else {
+        let result = combinations(states, groups, cache);
+        cache.insert(key, result);
+        result
+    }
+}
+
+/// Part two's spring: five copies of `spring`'s states separated by unknowns, and its groups
+/// five times over.
+pub fn unfold(spring: &Spring) -> Spring {
+    let groups = repeat_n(&spring.groups, 5).flatten().cloned().collect_vec();
+    let states = Itertools::intersperse(repeat_n(&spring.states, 5), &vec![SpringState::Unknown])
+        .flatten()
+        .cloned()
+        .collect_vec();
+    Spring { states, groups }
+}
+
+fn parse_input(input: &str) -> IResult<&str, Input> {
+    map(lines(parse_spring), |v| Input { springs: v })(input)
+}
+
+fn parse_spring(input: &str) -> IResult<&str, Spring> {
+    map(
+        separated_pair(parse_spring_state, space1, parse_group_sizes),
+        |(state, sizes)| Spring {
+            states: state,
+            groups: sizes,
+        },
+    )(input)
+}
+
+fn parse_spring_state(input: &str) -> IResult<&str, Vec<SpringState>> {
+    many1(map(one_of(".#?"), |c| match c {
+        '.' => SpringState::Operational,
+        '#' => SpringState::Damaged,
+        '?' => SpringState::Unknown,
+        _ => panic!("Impossible spring state char: {}", c),
+    }))(input)
+}
+
+fn parse_group_sizes(input: &str) -> IResult<&str, Vec<usize>> {
+    separated_list1(tag(","), map(nom_u64, |n| n as usize))(input)
+}
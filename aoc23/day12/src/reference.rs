@@ -0,0 +1,61 @@
+//! A naive reference solver, to check the memoised search against.
+
+use crate::SpringState;
+
+/// The number of ways to fill in the `Unknown` states so that the damaged springs form `groups`,
+/// by trying every way of filling them in.
+pub fn combinations(states: &[SpringState], groups: &[usize]) -> usize {
+    let unknowns: Vec<usize> = (0..states.len())
+        .filter(|&i| states[i] == SpringState::Unknown)
+        .collect();
+    (0..1u64 << unknowns.len())
+        .filter(|&filling| {
+            let mut states = states.to_vec();
+            for (bit, &i) in unknowns.iter().enumerate() {
+                states[i] = if filling & (1 << bit) != 0 {
+                    SpringState::Damaged
+                } else {
+                    SpringState::Operational
+                };
+            }
+            damaged_groups(&states) == groups
+        })
+        .count()
+}
+
+fn damaged_groups(states: &[SpringState]) -> Vec<usize> {
+    states
+        .split(|&state| state == SpringState::Operational)
+        .filter(|group| !group.is_empty())
+        .map(|group| group.len())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+    use std::collections::HashMap;
+
+    fn state() -> impl Strategy<Value = SpringState> {
+        prop_oneof![
+            Just(SpringState::Operational),
+            Just(SpringState::Damaged),
+            Just(SpringState::Unknown),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn agrees_with_combinations(
+            states in vec(state(), 0..14),
+            groups in vec(1..5usize, 0..5),
+        ) {
+            prop_assert_eq!(
+                crate::combinations(&states, &groups, &mut HashMap::new()),
+                combinations(&states, &groups)
+            );
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use aoc23_13::Day13;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn bench(c: &mut Criterion) {
+    aoc_common::bench::solution::<Day13>(c, 13);
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
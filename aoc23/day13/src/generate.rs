@@ -0,0 +1,74 @@
+//! Random notes of patterns. `size` is the number of patterns.
+
+use rand::Rng;
+
+/// Patterns of up to 17 x 17 with a perfect reflection, and another that is off by exactly one
+/// smudge, randomly across rows or columns and from either side.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let patterns: Vec<String> = (0..size.max(1)).map(|_| pattern(rng)).collect();
+    patterns.join("\n")
+}
+
+fn pattern(rng: &mut impl Rng) -> String {
+    let (rows, cols) = (rng.gen_range(2..=17), rng.gen_range(3..=17));
+    // Columns before 2 * mirror reflect about `mirror`, and rows before 2 * smudged about
+    // `smudged`, by reading every cell from its reflection nearest the top left.
+    let mirror = rng.gen_range(1..=(cols - 1) / 2);
+    let smudged = rng.gen_range(1..=rows / 2);
+    let fold = |i: usize, line: usize| {
+        if i < 2 * line {
+            i.min(2 * line - 1 - i)
+        } else {
+            i
+        }
+    };
+    let base: Vec<Vec<bool>> = (0..rows)
+        .map(|_| (0..cols).map(|_| rng.gen()).collect())
+        .collect();
+    let mut cells: Vec<Vec<bool>> = (0..rows)
+        .map(|r| {
+            (0..cols)
+                .map(|c| base[fold(r, smudged)][fold(c, mirror)])
+                .collect()
+        })
+        .collect();
+    // The smudge goes in a column outside the perfect reflection, so that stays perfect.
+    let (r, c) = (
+        rng.gen_range(0..2 * smudged),
+        rng.gen_range(2 * mirror..cols),
+    );
+    cells[r][c] = !cells[r][c];
+
+    if rng.gen() {
+        cells.reverse();
+    }
+    if rng.gen() {
+        cells.iter_mut().for_each(|row| row.reverse());
+    }
+    if rng.gen() {
+        cells = (0..cols)
+            .map(|c| (0..rows).map(|r| cells[r][c]).collect())
+            .collect();
+    }
+    cells
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|&rock| if rock { '#' } else { '.' })
+                .collect::<String>()
+                + "\n"
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day13;
+    use aoc_common::generate::exercise;
+
+    #[test]
+    fn generated_inputs_solve() {
+        exercise::<Day13>(generate, 20, 50);
+    }
+}
@@ -0,0 +1,9 @@
+use aoc23_14::Day14;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn bench(c: &mut Criterion) {
+    aoc_common::bench::solution::<Day14>(c, 14);
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
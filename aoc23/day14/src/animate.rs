@@ -0,0 +1,34 @@
+//! Animating the spin cycles.
+
+use crate::render::draw;
+use crate::{tilt, Day14};
+use anyhow::Result;
+use aoc_common::animate::Animation;
+use aoc_common::params::Params;
+use aoc_common::{Direction, Solution};
+use std::collections::HashSet;
+
+/// Each tilt of part two's spin cycles, until the platform is back in a state it's been in, or
+/// it's run as many as `params` says.
+pub fn animate(input: &str, params: &Params, animation: &mut Animation) -> Result<()> {
+    let mut rocks = Day14::parse(input)?;
+    let cycles: usize = params.get("cycles");
+    let mut past_states = HashSet::from([rocks.clone()]);
+    animation.step(|| draw(&rocks))?;
+    for _ in 0..cycles {
+        for dir in [
+            Direction::Up,
+            Direction::Left,
+            Direction::Down,
+            Direction::Right,
+        ] {
+            tilt(&mut rocks, dir);
+            animation.step(|| draw(&rocks))?;
+        }
+        if !past_states.insert(rocks.clone()) {
+            break;
+        }
+    }
+    animation.finish(|| draw(&rocks))?;
+    Ok(())
+}
@@ -0,0 +1,34 @@
+//! Random platforms of rocks. `size` is the width and height of the platform.
+
+use rand::Rng;
+
+/// A square platform with rounded rocks, cube-shaped rocks and empty space in random
+/// proportions.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let size = size.max(1);
+    let (round, cube) = (rng.gen_range(0.0..0.4), rng.gen_range(0.0..0.3));
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| match rng.gen::<f64>() {
+                    x if x < round => 'O',
+                    x if x < round + cube => '#',
+                    _ => '.',
+                })
+                .chain(Some('\n'))
+                .collect::<String>()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day14;
+    use aoc_common::generate::exercise;
+
+    #[test]
+    fn generated_inputs_solve() {
+        exercise::<Day14>(generate, 20, 50);
+    }
+}
@@ -0,0 +1,190 @@
+use anyhow::Result;
+use aoc_common::cycle;
+use aoc_common::grid::try_parse_grid;
+use aoc_common::params::{Param, Params};
+use aoc_common::parse::{BadChar, ParseError};
+use aoc_common::{Direction, Grid, Part, Solution};
+use itertools::Itertools;
+use std::fmt::Display;
+
+pub mod animate;
+#[cfg(any(test, feature = "generate"))]
+pub mod generate;
+pub mod render;
+
+#[derive(Eq, PartialEq, Debug, Clone, Copy, Hash)]
+pub enum RockState {
+    Empty,
+    Square,
+    Smooth,
+}
+
+impl TryFrom<char> for RockState {
+    type Error = BadChar;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(RockState::Empty),
+            '#' => Ok(RockState::Square),
+            'O' => Ok(RockState::Smooth),
+            found => Err(BadChar {
+                found,
+                expected: ".#O",
+            }),
+        }
+    }
+}
+
+impl From<RockState> for char {
+    fn from(value: RockState) -> Self {
+        match value {
+            RockState::Empty => '.',
+            RockState::Square => '#',
+            RockState::Smooth => 'O',
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Rocks {
+    pub rocks: Grid<RockState>,
+}
+
+impl Display for Rocks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let string = self
+            .rocks
+            .iter_rows()
+            .map(|row| row.map(|&item| -> char { item.into() }).join(""))
+            .join("\n");
+        write!(f, "{}", string)
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Rocks;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    const PARAMS: &'static [Param] = &[Param::new::<usize>(
+        "cycles",
+        "1000000000",
+        "Spin cycles to run before weighing the load",
+    )
+    .part(Part::Two)];
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        Self::part_two_with(input, &Params::defaults(Self::PARAMS, Part::Two))
+    }
+
+    fn part_two_with(input: &Self::Input, params: &Params) -> Self::PartTwo {
+        part_two(input, params.get("cycles"))
+    }
+}
+
+fn part_one(input: &Rocks) -> usize {
+    let mut grid = input.clone();
+    tilt(&mut grid, Direction::Up);
+    rocks_value(&grid)
+}
+
+/// The load on the north beams after `cycles` spin cycles.
+fn part_two(input: &Rocks, cycles: usize) -> usize {
+    let history = cycle::iterate(input.clone(), |rocks| {
+        let mut rocks = rocks.clone();
+        spin_cycle(&mut rocks);
+        rocks
+    });
+    rocks_value(history.nth(cycles))
+}
+
+/// Tilt north, west, south and then east.
+pub fn spin_cycle(rocks: &mut Rocks) {
+    tilt(rocks, Direction::Up);
+    tilt(rocks, Direction::Left);
+    tilt(rocks, Direction::Down);
+    tilt(rocks, Direction::Right);
+}
+
+fn view_get<T>(grid: &Grid<T>, outer: usize, inner: usize, direction: Direction) -> &T {
+    let (col, row) = match direction {
+        Direction::Up => (outer, inner),
+        Direction::Down => (outer, grid.rows() - inner - 1),
+        Direction::Right => (grid.cols() - inner - 1, outer),
+        Direction::Left => (inner, outer),
+    };
+    grid.get(row, col).unwrap()
+}
+
+fn view_get_mut<T>(grid: &mut Grid<T>, outer: usize, inner: usize, direction: Direction) -> &mut T {
+    let (col, row) = match direction {
+        Direction::Up => (outer, inner),
+        Direction::Down => (outer, grid.rows() - inner - 1),
+        Direction::Right => (grid.cols() - inner - 1, outer),
+        Direction::Left => (inner, outer),
+    };
+    grid.get_mut(row, col).unwrap()
+}
+
+/// Roll every smooth rock as far as it will go towards `dir`.
+pub fn tilt(rocks: &mut Rocks, dir: Direction) {
+    let (out_max, in_max) = match dir {
+        Direction::Up | Direction::Down => (rocks.rocks.cols(), rocks.rocks.rows()),
+        Direction::Left | Direction::Right => (rocks.rocks.rows(), rocks.rocks.cols()),
+    };
+
+    for outer in 0..out_max {
+        let mut target = 0;
+        for inner in 0..in_max {
+            let val = *view_get(&rocks.rocks, outer, inner, dir);
+
+            if inner == target {
+                if val != RockState::Empty {
+                    target += 1;
+                }
+                continue;
+            }
+
+            match val {
+                RockState::Empty => {}
+                RockState::Square => {
+                    target = inner + 1;
+                }
+                RockState::Smooth => {
+                    *view_get_mut(&mut rocks.rocks, outer, target, dir) = val;
+                    *view_get_mut(&mut rocks.rocks, outer, inner, dir) = RockState::Empty;
+                    target += 1;
+                }
+            }
+        }
+    }
+}
+
+/// The load on the north support beams.
+pub fn rocks_value(rocks: &Rocks) -> usize {
+    rocks
+        .rocks
+        .iter_rows()
+        .enumerate()
+        .map(|(row_idx, row)| {
+            let row_score = rocks.rocks.rows() - row_idx;
+            row.filter(|val| **val == RockState::Smooth).count() * row_score
+        })
+        .sum()
+}
+
+fn parse_input(input: &str) -> Result<Rocks, ParseError> {
+    Ok(Rocks {
+        rocks: try_parse_grid(input, RockState::try_from)?,
+    })
+}
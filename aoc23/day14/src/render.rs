@@ -0,0 +1,25 @@
+//! Drawing the platform.
+
+use crate::{tilt, Day14, RockState, Rocks};
+use anyhow::Result;
+use aoc_common::render::{Canvas, Color, Style};
+use aoc_common::{Direction, Solution};
+
+/// The platform once it's been tilted north, as in part one.
+pub fn render(input: &str) -> Result<Canvas> {
+    let mut rocks = Day14::parse(input)?;
+    tilt(&mut rocks, Direction::Up);
+    Ok(draw(&rocks))
+}
+
+/// The rocks, with the round ones brightest.
+pub fn draw(rocks: &Rocks) -> Canvas {
+    Canvas::new(&rocks.rocks, |&rock| {
+        let color = match rock {
+            RockState::Empty => Color::DARK_GREY,
+            RockState::Square => Color::GREY,
+            RockState::Smooth => Color::WHITE,
+        };
+        Style::new(rock.into(), color)
+    })
+}
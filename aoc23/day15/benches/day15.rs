@@ -0,0 +1,9 @@
+use aoc23_15::Day15;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn bench(c: &mut Criterion) {
+    aoc_common::bench::solution::<Day15>(c, 15);
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -0,0 +1,43 @@
+//! Random initialization sequences. `size` is the number of steps.
+
+use rand::Rng;
+
+/// Steps that put a lens of focal length 1 to 9 in a box or take one out, with labels drawn
+/// from a small pool so that the same lens is often replaced or removed.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let labels: Vec<String> = (0..size.div_ceil(3).max(1))
+        .map(|_| {
+            (0..rng.gen_range(1..=6))
+                .map(|_| rng.gen_range('a'..='z'))
+                .collect()
+        })
+        .collect();
+    let steps: Vec<String> = (0..size.max(1))
+        .map(|_| {
+            let label = &labels[rng.gen_range(0..labels.len())];
+            if rng.gen_bool(0.3) {
+                format!("{}-", label)
+            } else {
+                format!("{}={}", label, rng.gen_range(1..=9))
+            }
+        })
+        .collect();
+    steps.join(",") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day15;
+    use aoc_common::generate::{exercise, exercise_streaming};
+
+    #[test]
+    fn generated_inputs_solve() {
+        exercise::<Day15>(generate, 20, 50);
+    }
+
+    #[test]
+    fn generated_inputs_stream() {
+        exercise_streaming::<Day15>(generate, 20, 50);
+    }
+}
@@ -0,0 +1,328 @@
+use anyhow::Result;
+use aoc_common::parse::ParseError;
+use aoc_common::stream::Streaming;
+use aoc_common::Solution;
+use array_init::array_init;
+use std::ops::Range;
+
+#[cfg(any(test, feature = "generate"))]
+pub mod generate;
+
+#[derive(Clone)]
+pub struct Lens {
+    pub label: String,
+    pub value: u8,
+}
+
+pub struct Snowbox(pub Vec<Lens>);
+
+impl Snowbox {
+    fn upsert(&mut self, label: String, value: u8) {
+        let existing = self.0.iter().enumerate().find_map(|(idx, lens)| {
+            if lens.label.eq(&label) {
+                Some(idx)
+            } else {
+                None
+            }
+        });
+        if let Some(idx) = existing {
+            *self.0.get_mut(idx).unwrap() = Lens { label, value };
+        } else {
+            self.0.push(Lens { label, value });
+        }
+    }
+
+    fn delete(&mut self, label: String) {
+        let existing = self.0.iter().enumerate().find_map(|(idx, lens)| {
+            if lens.label.eq(&label) {
+                Some(idx)
+            } else {
+                None
+            }
+        });
+        if let Some(idx) = existing {
+            self.0.remove(idx);
+        }
+    }
+}
+
+/// The HASHMAP: 256 boxes of lenses, each lens in the box its label hashes to.
+pub struct Snowmap {
+    pub boxes: [Snowbox; 256],
+}
+
+impl Snowmap {
+    pub fn new() -> Self {
+        Snowmap {
+            boxes: array_init(|_| Snowbox(Vec::new())),
+        }
+    }
+
+    pub fn upsert(&mut self, label: String, value: u8) {
+        let hash = snow_hash(&label);
+        self.boxes[hash as usize].upsert(label, value);
+    }
+
+    pub fn delete(&mut self, label: String) {
+        let hash = snow_hash(&label);
+        self.boxes[hash as usize].delete(label);
+    }
+
+    pub fn apply(&mut self, instruction: Instruction) {
+        match instruction {
+            Instruction::Set { label, value } => {
+                self.upsert(label, value);
+            }
+            Instruction::Delete { label } => {
+                self.delete(label);
+            }
+        }
+    }
+
+    /// The sum over every lens of its box number times its slot number times its focal length.
+    pub fn focusing_power(&self) -> u64 {
+        self.boxes
+            .iter()
+            .enumerate()
+            .map(|(box_idx, snowbox)| {
+                snowbox
+                    .0
+                    .iter()
+                    .enumerate()
+                    .map(|(lens_idx, lens)| {
+                        ((box_idx + 1) * (lens_idx + 1) * (lens.value as usize)) as u64
+                    })
+                    .sum::<u64>()
+            })
+            .sum()
+    }
+}
+
+impl Default for Snowmap {
+    fn default() -> Self {
+        Snowmap::new()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Instruction {
+    Set { label: String, value: u8 },
+    Delete { label: String },
+}
+
+/// A step of the initialization sequence: its text, to hash, and what it does.
+#[derive(Debug)]
+pub struct Step {
+    pub text: String,
+    pub instruction: Instruction,
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<Step>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        part_two(input)
+    }
+}
+
+/// The steps so far: the sum of their hashes, and the lenses they've left in the boxes.
+#[derive(Default)]
+pub struct State {
+    hashes: u64,
+    snowmap: Snowmap,
+}
+
+impl Streaming for Day15 {
+    type State = State;
+    const SEPARATOR: u8 = b',';
+
+    fn feed(state: &mut State, record: &str) -> Result<()> {
+        let Some(range) = trim_step(record, 0..record.len()) else {
+            return Ok(());
+        };
+        let instruction = parse_step(record, range.clone())?;
+        state.hashes += snow_hash(&record[range]) as u64;
+        state.snowmap.apply(instruction);
+        Ok(())
+    }
+
+    fn finish_one(state: &State) -> u64 {
+        state.hashes
+    }
+
+    fn finish_two(state: &State) -> u64 {
+        state.snowmap.focusing_power()
+    }
+}
+
+fn part_one(input: &[Step]) -> u64 {
+    input.iter().map(|step| snow_hash(&step.text) as u64).sum()
+}
+
+fn part_two(input: &[Step]) -> u64 {
+    let mut snowmap = Snowmap::new();
+    for step in input {
+        snowmap.apply(step.instruction.clone());
+    }
+    snowmap.focusing_power()
+}
+
+/// Parse a step, `label=N` or `label-`.
+pub fn parse_instr(instr: &str) -> Result<Instruction, ParseError> {
+    parse_step(instr, 0..instr.len())
+}
+
+/// Parse the step at `range` of `input`, for errors located in `input`.
+fn parse_step(input: &str, range: Range<usize>) -> Result<Instruction, ParseError> {
+    let step = &input[range.clone()];
+    let error =
+        |offset: usize, message: String| ParseError::at(input, range.start + offset, message);
+    // Only ASCII can be hashed, and whitespace, like a newline, would only be around a step.
+    if let Some((offset, c)) = step.char_indices().find(|(_, c)| !c.is_ascii_graphic()) {
+        let message = format!("expected a printable ASCII character, found {:?}", c);
+        return Err(error(offset, message));
+    }
+    if let Some(label) = step.strip_suffix('-') {
+        let label = label.to_owned();
+        return Ok(Instruction::Delete { label });
+    }
+    let Some((label, value)) = step.split_once('=') else {
+        return Err(error(
+            0,
+            format!("expected LABEL=N or LABEL-, found {:?}", step),
+        ));
+    };
+    let Ok(value) = value.parse::<u8>() else {
+        let message = format!("expected a focal length up to 255, found {:?}", value);
+        return Err(error(label.len() + 1, message));
+    };
+    let label = label.to_owned();
+    Ok(Instruction::Set { label, value })
+}
+
+/// The HASH algorithm.
+pub fn snow_hash(string: &str) -> u8 {
+    string.chars().fold(0u8, hash_step)
+}
+
+fn hash_step(init: u8, next: char) -> u8 {
+    // Determine the ASCII code for the current character of the string.
+    // Increase the current value by the ASCII code you just determined.
+    // Set the current value to itself multiplied by 17.
+    // Set the current value to the remainder of dividing itself by 256.
+
+    let mut state = init as u32;
+    assert!(next.is_ascii());
+    assert!((next as u32) < 128);
+    state += next as u32;
+    state *= 17;
+    state %= 256;
+    state as u8
+}
+
+/// The step at `range` of `input` without the whitespace around it, or `None` if it's blank.
+/// Whether the input is read whole or streamed, each step is what's between the commas, trimmed
+/// like this.
+fn trim_step(input: &str, range: Range<usize>) -> Option<Range<usize>> {
+    let step = &input[range.clone()];
+    let start = range.start + step.len() - step.trim_start().len();
+    let end = range.start + step.trim_end().len();
+    (start < end).then_some(start..end)
+}
+
+fn parse_input(input: &str) -> Result<Vec<Step>, ParseError> {
+    let mut steps = Vec::new();
+    let mut start = 0;
+    for text in input.split(',') {
+        if let Some(range) = trim_step(input, start..start + text.len()) {
+            steps.push(Step {
+                text: input[range.clone()].to_owned(),
+                instruction: parse_step(input, range)?,
+            });
+        }
+        start += text.len() + 1;
+    }
+    Ok(steps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::stream::stream;
+    use std::io::Cursor;
+
+    #[test]
+    fn parses_steps() {
+        let set = Instruction::Set {
+            label: "rn".to_string(),
+            value: 1,
+        };
+        assert_eq!(parse_instr("rn=1"), Ok(set));
+        let delete = Instruction::Delete {
+            label: "cm".to_string(),
+        };
+        assert_eq!(parse_instr("cm-"), Ok(delete));
+    }
+
+    #[test]
+    fn errors_point_into_the_input() {
+        let err = Day15::parse("rn=1,cm-,\nqp=x").unwrap_err();
+        let err = err.downcast::<ParseError>().unwrap();
+        let expected = "expected a focal length up to 255, found \"x\"";
+        assert_eq!(err, ParseError::new(2, 4, expected));
+
+        let err = stream::<Day15>(&mut Cursor::new("rn=1,cm-,qp=x\n"))
+            .err()
+            .unwrap();
+        let err = err.downcast::<ParseError>().unwrap();
+        assert_eq!(err, ParseError::new(1, 13, expected));
+
+        let err = stream::<Day15>(&mut Cursor::new("rn=1, é-")).err().unwrap();
+        let err = err.downcast::<ParseError>().unwrap();
+        let expected = "expected a printable ASCII character, found 'é'";
+        assert_eq!(err, ParseError::new(1, 7, expected));
+
+        let err = Day15::parse("rn=1,cm").unwrap_err();
+        let err = err.downcast::<ParseError>().unwrap();
+        let expected = "expected LABEL=N or LABEL-, found \"cm\"";
+        assert_eq!(err, ParseError::new(1, 6, expected));
+    }
+
+    #[test]
+    fn streams_as_it_parses() {
+        let solve = |input: &str| {
+            let steps = Day15::parse(input).map_err(|err| err.to_string())?;
+            Ok::<_, String>((Day15::part_one(&steps), Day15::part_two(&steps)))
+        };
+        let stream = |input: &str| {
+            let state = stream::<Day15>(&mut Cursor::new(input)).map_err(|err| err.to_string())?;
+            Ok::<_, String>((Day15::finish_one(&state), Day15::finish_two(&state)))
+        };
+        for input in [
+            "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7\n",
+            "rn=1 ,\n cm-,,qp=3\n,pc=4,\n",
+            "\n,rn=1,",
+            "",
+            "rn=1,c m-",
+            "rn=1,\ncm-,qp=\n3",
+        ] {
+            assert_eq!(stream(input), solve(input), "{:?}", input);
+        }
+        assert_eq!(
+            solve("rn=1 ,\n cm-,,qp=3\n,pc=4,\n"),
+            solve("rn=1,cm-,qp=3,pc=4")
+        );
+    }
+}
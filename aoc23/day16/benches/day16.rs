@@ -0,0 +1,9 @@
+use aoc23_16::Day16;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn bench(c: &mut Criterion) {
+    aoc_common::bench::solution::<Day16>(c, 16);
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
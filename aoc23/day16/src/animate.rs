@@ -0,0 +1,39 @@
+//! Animating the beam.
+
+use crate::render::draw;
+use crate::{get_next_beams, Beam, Day16, Illumination};
+use anyhow::Result;
+use aoc_common::animate::Animation;
+use aoc_common::render::Color;
+use aoc_common::{Direction, Point, Solution};
+use std::collections::HashSet;
+
+/// Part one's beam spreading through the contraption, a tile at a time, with the heads of its
+/// branches highlighted.
+pub fn animate(input: &str, animation: &mut Animation) -> Result<()> {
+    let ops = Day16::parse(input)?;
+    let mut illumination = Illumination::new(&ops);
+    let mut visited = HashSet::new();
+    let mut heads = vec![Beam::new(Point::new(0, 0), Direction::Right)];
+    while !heads.is_empty() {
+        for beam in &heads {
+            *illumination.grid.get_mut(beam.loc.y, beam.loc.x).unwrap() = true;
+            visited.insert(*beam);
+        }
+        animation.step(|| {
+            let mut canvas = draw(&ops, &illumination);
+            canvas.highlight(heads.iter().map(|beam| beam.loc), Color::YELLOW);
+            canvas
+        })?;
+        heads = heads
+            .iter()
+            .flat_map(|beam| get_next_beams(&ops, beam))
+            .flatten()
+            .filter(|beam| !visited.contains(beam))
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+    }
+    animation.finish(|| draw(&ops, &illumination))?;
+    Ok(())
+}
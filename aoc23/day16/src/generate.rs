@@ -0,0 +1,36 @@
+//! Random contraptions. `size` is the width and height of the contraption.
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// A square contraption of mirrors and splitters scattered over empty space.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let size = size.max(1);
+    let density = rng.gen_range(0.05..0.3);
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| {
+                    if rng.gen_bool(density) {
+                        *b"|-/\\".choose(rng).unwrap() as char
+                    } else {
+                        '.'
+                    }
+                })
+                .chain(Some('\n'))
+                .collect::<String>()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day16;
+    use aoc_common::generate::exercise;
+
+    #[test]
+    fn generated_inputs_solve() {
+        exercise::<Day16>(generate, 20, 50);
+    }
+}
@@ -0,0 +1,302 @@
+use anyhow::Result;
+use aoc_common::grid::try_parse_grid;
+use aoc_common::parse::{BadChar, ParseError};
+use aoc_common::{Direction, Grid, Point, Solution};
+use itertools::Itertools;
+use rayon::prelude::*;
+use std::collections::HashSet;
+use std::fmt::Display;
+
+pub mod animate;
+#[cfg(any(test, feature = "generate"))]
+pub mod generate;
+pub mod render;
+
+pub type Location = Point<usize>;
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum OpticalPart {
+    Empty,
+    SplitterUpDown,
+    SplitterLeftRight,
+    // reflects light coming from the left to down and vice versa; same as up right
+    MirrorDown,
+    // reflects light coming from the left to up and vice versa; same as down right
+    MirrorUp,
+}
+
+impl TryFrom<char> for OpticalPart {
+    type Error = BadChar;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '|' => Ok(OpticalPart::SplitterUpDown),
+            '-' => Ok(OpticalPart::SplitterLeftRight),
+            '/' => Ok(OpticalPart::MirrorUp),
+            '\\' => Ok(OpticalPart::MirrorDown),
+            '.' => Ok(OpticalPart::Empty),
+            found => Err(BadChar {
+                found,
+                expected: "|-/\\.",
+            }),
+        }
+    }
+}
+
+impl Display for OpticalPart {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                OpticalPart::Empty => '.',
+                OpticalPart::MirrorDown => '\\',
+                OpticalPart::MirrorUp => '/',
+                OpticalPart::SplitterLeftRight => '-',
+                OpticalPart::SplitterUpDown => '|',
+            }
+        )
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Beam {
+    pub loc: Location,
+    pub dir: Direction,
+}
+
+impl Beam {
+    pub fn new(loc: Location, dir: Direction) -> Self {
+        Self { loc, dir }
+    }
+}
+
+pub struct Opgrid {
+    pub grid: Grid<OpticalPart>,
+}
+
+impl Display for Opgrid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let string = self
+            .grid
+            .iter_rows()
+            .map(|row| row.map(|item| item.to_string()).join(""))
+            .join("\n");
+        write!(f, "{}", string)
+    }
+}
+
+/// Which tiles a beam has passed through.
+pub struct Illumination {
+    pub grid: Grid<bool>,
+}
+
+impl Display for Illumination {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let string = self
+            .grid
+            .iter_rows()
+            .map(|row| row.map(|x| if *x { "#" } else { "." }).join(""))
+            .join("\n");
+        write!(f, "{}", string)
+    }
+}
+
+impl Illumination {
+    pub fn new(ops: &Opgrid) -> Self {
+        Illumination {
+            grid: Grid::init(ops.grid.rows(), ops.grid.cols(), false),
+        }
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Opgrid;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        part_two(input)
+    }
+}
+
+fn part_one(ops: &Opgrid) -> usize {
+    illumination_score(ops, Beam::new(Point::new(0, 0), Direction::Right))
+}
+
+fn part_two(ops: &Opgrid) -> usize {
+    let rights = (0..ops.grid.rows()).map(|r| Beam::new(Point::new(0, r), Direction::Right));
+    let downs = (0..ops.grid.cols()).map(|c| Beam::new(Point::new(c, 0), Direction::Down));
+    let lefts = (0..ops.grid.rows())
+        .map(|r| Beam::new(Point::new(ops.grid.cols() - 1, r), Direction::Left));
+    let ups =
+        (0..ops.grid.cols()).map(|c| Beam::new(Point::new(c, ops.grid.rows() - 1), Direction::Up));
+    let beams = rights.chain(downs).chain(lefts).chain(ups).collect_vec();
+
+    beams
+        .par_iter()
+        .map(|beam| illumination_score(ops, *beam))
+        .max()
+        .unwrap()
+}
+
+/// The number of tiles energized by `beam`.
+pub fn illumination_score(ops: &Opgrid, beam: Beam) -> usize {
+    let mut illum = Illumination::new(ops);
+    illuminate(ops, &mut illum, beam);
+    illum.grid.iter().map(|x| if *x { 1 } else { 0 }).sum()
+}
+
+/// Mark every tile `beam` passes through in `illum`.
+pub fn illuminate(ops: &Opgrid, illum: &mut Illumination, beam: Beam) {
+    propagate_beam(ops, illum, beam, &mut HashSet::new());
+}
+
+fn propagate_beam(ops: &Opgrid, illum: &mut Illumination, beam: Beam, visited: &mut HashSet<Beam>) {
+    // cycle check
+    if visited.contains(&beam) {
+        return;
+    }
+
+    // illuminate
+    *illum.grid.get_mut(beam.loc.y, beam.loc.x).unwrap() = true;
+    visited.insert(beam);
+
+    let propagated_beams = get_next_beams(ops, &beam);
+    for next_beam in propagated_beams.into_iter().flatten() {
+        propagate_beam(ops, illum, next_beam, visited);
+    }
+}
+
+fn bounded_propagate_loc(ops: &Opgrid, loc: &Location, dir: &Direction) -> Option<Beam> {
+    loc.step(*dir, ops.grid.cols(), ops.grid.rows())
+        .map(|next| Beam::new(next, *dir))
+}
+
+/// Where `beam` goes after its current tile: one beam, two if it is split, or none if it
+/// leaves the grid.
+pub fn get_next_beams(ops: &Opgrid, beam: &Beam) -> [Option<Beam>; 2] {
+    let cell = ops.grid.get(beam.loc.y, beam.loc.x).unwrap();
+
+    match cell {
+        OpticalPart::Empty => [bounded_propagate_loc(ops, &beam.loc, &beam.dir), None],
+        OpticalPart::SplitterUpDown => match beam.dir {
+            Direction::Left | Direction::Right => [
+                bounded_propagate_loc(ops, &beam.loc, &Direction::Up),
+                bounded_propagate_loc(ops, &beam.loc, &Direction::Down),
+            ],
+            _ => [bounded_propagate_loc(ops, &beam.loc, &beam.dir), None],
+        },
+        OpticalPart::SplitterLeftRight => match beam.dir {
+            Direction::Up | Direction::Down => [
+                bounded_propagate_loc(ops, &beam.loc, &Direction::Left),
+                bounded_propagate_loc(ops, &beam.loc, &Direction::Right),
+            ],
+            _ => [bounded_propagate_loc(ops, &beam.loc, &beam.dir), None],
+        },
+        OpticalPart::MirrorDown => {
+            let dir = match beam.dir {
+                Direction::Left => Direction::Up,
+                Direction::Right => Direction::Down,
+                Direction::Up => Direction::Left,
+                Direction::Down => Direction::Right,
+            };
+            [bounded_propagate_loc(ops, &beam.loc, &dir), None]
+        }
+        OpticalPart::MirrorUp => {
+            let dir = match beam.dir {
+                Direction::Left => Direction::Down,
+                Direction::Right => Direction::Up,
+                Direction::Up => Direction::Right,
+                Direction::Down => Direction::Left,
+            };
+            [bounded_propagate_loc(ops, &beam.loc, &dir), None]
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<Opgrid, ParseError> {
+    Ok(Opgrid {
+        grid: try_parse_grid(input, OpticalPart::try_from)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_parse() {
+        let input = "\\/.\n|-.\n";
+        let parsed = parse_input(input).unwrap();
+        let grid = &parsed.grid;
+        assert_eq!(grid.cols(), 3);
+        assert_eq!(grid.rows(), 2);
+        assert_eq!(grid.get(0, 0).unwrap(), &OpticalPart::MirrorDown);
+        assert_eq!(grid.get(0, 1).unwrap(), &OpticalPart::MirrorUp);
+        assert_eq!(grid.get(0, 2).unwrap(), &OpticalPart::Empty);
+        assert_eq!(grid.get(1, 0).unwrap(), &OpticalPart::SplitterUpDown);
+        assert_eq!(grid.get(1, 1).unwrap(), &OpticalPart::SplitterLeftRight);
+        assert_eq!(grid.get(1, 2).unwrap(), &OpticalPart::Empty);
+    }
+
+    #[test]
+    fn example() {
+        let input_s = fs::read_to_string("../examples/day16.txt").unwrap();
+        let input = parse_input(&input_s).unwrap();
+        assert_eq!(part_one(&input), 46);
+    }
+
+    #[test]
+    fn tsplit() {
+        let input = "..|..\n.....\n..-..";
+        let parsed = super::parse_input(input).unwrap();
+        assert_eq!(part_one(&parsed), 9);
+    }
+
+    #[test]
+    fn mirrors() {
+        let input = "...\\...\n.......\n-......\n.......\n\\../...";
+        let parsed = super::parse_input(input).unwrap();
+        assert_eq!(part_one(&parsed), 18);
+    }
+
+    #[test]
+    fn loop_with_initial_dirchange() {
+        let input = "|....-\n......\n......\n-....|";
+        let parsed = super::parse_input(input).unwrap();
+        assert_eq!(part_one(&parsed), 16);
+    }
+
+    #[test]
+    fn multivisitor() {
+        let input = "......|...\\..\\...\n..../........|...\n....\\.-.../......\n......|....../...\n.................";
+        let parsed = super::parse_input(input).unwrap();
+        assert_eq!(part_one(&parsed), 41);
+    }
+
+    #[test]
+    fn misc_testcase() {
+        let input = "\\........-.........\\................................|...\n......-/.............|-.../.....|...........././..\\.....\n-.........................|.....\\...................|.\\.\n.......-........../.......\\.........|..../........-.-|..\n";
+        let parsed = super::parse_input(input).unwrap();
+        assert_eq!(part_one(&parsed), 89);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input_s = fs::read_to_string("../examples/day16.txt").unwrap();
+        let input = parse_input(&input_s).unwrap();
+        assert_eq!(part_two(&input), 51);
+    }
+}
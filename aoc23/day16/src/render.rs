@@ -0,0 +1,36 @@
+//! Drawing the contraption.
+
+use crate::{illuminate, Beam, Day16, Illumination, Opgrid, OpticalPart};
+use anyhow::Result;
+use aoc_common::render::{Canvas, Color, Style};
+use aoc_common::{Direction, Point, Solution};
+
+/// The contraption with the tiles energized by part one's beam highlighted.
+pub fn render(input: &str) -> Result<Canvas> {
+    let ops = Day16::parse(input)?;
+    let mut illumination = Illumination::new(&ops);
+    illuminate(
+        &ops,
+        &mut illumination,
+        Beam::new(Point::new(0, 0), Direction::Right),
+    );
+    Ok(draw(&ops, &illumination))
+}
+
+/// The contraption with the energized tiles in `illumination` highlighted.
+pub fn draw(ops: &Opgrid, illumination: &Illumination) -> Canvas {
+    let mut canvas = Canvas::new(&ops.grid, |part| {
+        let glyph = part.to_string().chars().next().unwrap();
+        match part {
+            OpticalPart::Empty => Style::new(glyph, Color::DARK_GREY),
+            _ => Style::new(glyph, Color::WHITE),
+        }
+    });
+    let energized = illumination
+        .grid
+        .indexed_iter()
+        .filter(|(_, &lit)| lit)
+        .map(|((row, col), _)| Point::new(col, row));
+    canvas.highlight(energized, Color::rgb(120, 90, 0));
+    canvas
+}
@@ -0,0 +1,56 @@
+use aoc23_17::{crucible_search, old, Day17, Problem};
+use aoc_common::shortest_path::Backend;
+use aoc_common::Solution;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn bench(c: &mut Criterion) {
+    aoc_common::bench::solution::<Day17>(c, 17);
+}
+
+// The petgraph solution in old.rs against the current one.
+fn baseline(c: &mut Criterion) {
+    for (name, input) in aoc_common::bench::inputs(17) {
+        let current = Day17::parse(&input).unwrap();
+        let petgraph = old::parse_input(&input);
+
+        let mut group = c.benchmark_group(format!("day17-baseline/{}", name));
+        group.sample_size(10);
+        group.bench_function("petgraph part one", |b| {
+            b.iter(|| old::part_one(black_box(&petgraph)))
+        });
+        group.bench_function("current part one", |b| {
+            b.iter(|| Day17::part_one(black_box(&current)))
+        });
+        group.bench_function("petgraph part two", |b| {
+            b.iter(|| old::part_two(black_box(&petgraph)))
+        });
+        group.bench_function("current part two", |b| {
+            b.iter(|| Day17::part_two(black_box(&current)))
+        });
+        group.finish();
+    }
+}
+
+// The shortest path backends against each other, on part two.
+fn backends(c: &mut Criterion) {
+    for (name, input) in aoc_common::bench::inputs(17) {
+        let grid = Day17::parse(&input).unwrap();
+        let problem = Problem {
+            grid: &grid,
+            min_steps: 4,
+            max_steps: 10,
+        };
+
+        let mut group = c.benchmark_group(format!("day17-backends/{}", name));
+        group.sample_size(10);
+        for backend in [Backend::Dial, Backend::Dijkstra, Backend::AStar] {
+            group.bench_function(format!("{:?}", backend), |b| {
+                b.iter(|| crucible_search(black_box(&problem), backend))
+            });
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench, baseline, backends);
+criterion_main!(benches);
@@ -0,0 +1,29 @@
+//! Random city maps. `size` is the width and height of the city, at least 5.
+
+use rand::Rng;
+
+/// A square city of blocks losing 1 to 9 heat. It's at least 5 blocks across, so that an
+/// ultra crucible can still reach the far corner.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let size = size.max(5);
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| char::from_digit(rng.gen_range(1..=9), 10).unwrap())
+                .chain(Some('\n'))
+                .collect::<String>()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day17;
+    use aoc_common::generate::exercise;
+
+    #[test]
+    fn generated_inputs_solve() {
+        exercise::<Day17>(generate, 20, 20);
+    }
+}
@@ -0,0 +1,359 @@
+use anyhow::Result;
+use aoc_common::grid::try_parse_grid;
+use aoc_common::params::{Param, Params};
+use aoc_common::parse::{BadChar, ParseError};
+use aoc_common::shortest_path::{shortest_cost, shortest_path, Backend, StateSpace};
+use aoc_common::{Answer, Direction, Grid, Part, Point, Solution};
+use tracing::warn;
+
+#[cfg(any(test, feature = "generate"))]
+pub mod generate;
+#[doc(hidden)]
+pub mod old;
+pub mod render;
+
+#[derive(PartialEq, Eq, Clone, Copy)]
+struct NodeId {
+    x: usize,
+    y: usize,
+    steps: usize,
+    direction: Option<Direction>,
+}
+
+impl NodeId {
+    fn new(x: usize, y: usize, steps: usize, direction: Option<Direction>) -> Self {
+        Self {
+            x,
+            y,
+            steps,
+            direction,
+        }
+    }
+
+    fn to_index(self, _max_x: usize, max_y: usize, max_steps: usize) -> usize {
+        // note: max x/y is exclusive, max steps is inclusive
+        // note: steps = 0 is not a supported state
+        assert!(self.steps != 0);
+        let direction = self.direction.expect("start node has no index");
+        let mut id = self.x;
+        id = (id * max_y) + self.y;
+        id = (id * max_steps) + (self.steps - 1);
+        id = (id * 4) + direction.index();
+        id
+    }
+}
+
+/// A crucible's route to find: across `grid` of heat losses, moving between `min_steps` and
+/// `max_steps` in a straight line before each turn.
+pub struct Problem<'a> {
+    pub grid: &'a Grid<usize>,
+    pub min_steps: usize,
+    pub max_steps: usize,
+}
+
+impl<'a> Problem<'a> {
+    /// The problem with the step limits of `params`. No straight run can be longer than the
+    /// grid, so `max_steps` is capped at its larger dimension, which bounds the states to search.
+    fn new(grid: &'a Grid<usize>, params: &Params) -> Problem<'a> {
+        let max_steps: usize = params.get("max_steps");
+        Problem {
+            grid,
+            min_steps: params.get("min_steps"),
+            max_steps: max_steps.min(grid.cols().max(grid.rows())),
+        }
+    }
+
+    fn node_to_index(&self, node: &NodeId) -> usize {
+        node.to_index(self.grid.cols(), self.grid.rows(), self.max_steps)
+    }
+
+    fn max_index(&self) -> usize {
+        self.grid.cols() * self.grid.rows() * self.max_steps * 4
+    }
+}
+
+/// The least heat lost getting to the far corner, if the crucible can get there at all: it
+/// can't if it must move further than the grid is wide or tall before turning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeatLoss(pub Option<usize>);
+
+impl Answer for HeatLoss {
+    fn answer(&self) -> Option<String> {
+        self.0.map(|heat| heat.to_string())
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Grid<usize>;
+    type PartOne = HeatLoss;
+    type PartTwo = HeatLoss;
+
+    const PARAMS: &'static [Param] = &[
+        Param::new::<usize>("min_steps", "1", "Fewest blocks to move before turning")
+            .part(Part::One)
+            .at_least(1)
+            .no_more_than("max_steps"),
+        Param::new::<usize>("max_steps", "3", "Most blocks to move before turning")
+            .part(Part::One)
+            .at_most(1000),
+        Param::new::<usize>("min_steps", "4", "Fewest blocks to move before turning")
+            .part(Part::Two)
+            .at_least(1)
+            .no_more_than("max_steps"),
+        Param::new::<usize>("max_steps", "10", "Most blocks to move before turning")
+            .part(Part::Two)
+            .at_most(1000),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        Self::part_one_with(input, &Params::defaults(Self::PARAMS, Part::One))
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        Self::part_two_with(input, &Params::defaults(Self::PARAMS, Part::Two))
+    }
+
+    fn part_one_with(input: &Self::Input, params: &Params) -> Self::PartOne {
+        heat_loss(&Problem::new(input, params))
+    }
+
+    fn part_two_with(input: &Self::Input, params: &Params) -> Self::PartTwo {
+        heat_loss(&Problem::new(input, params))
+    }
+}
+
+/// The answer to `problem`, with a warning if there's none.
+fn heat_loss(problem: &Problem) -> HeatLoss {
+    let heat = crucible_walk(problem);
+    if heat.is_none() {
+        warn!(
+            min_steps = problem.min_steps,
+            "the crucible can't reach the far corner"
+        );
+    }
+    HeatLoss(heat)
+}
+
+/// The least heat lost getting from the top left to the bottom right corner, or `None` if the
+/// crucible can't get there.
+pub fn crucible_walk(problem: &Problem) -> Option<usize> {
+    crucible_search(problem, Backend::Dial)
+}
+
+/// As [`crucible_walk`], searching with `backend`.
+pub fn crucible_search(problem: &Problem, backend: Backend) -> Option<usize> {
+    shortest_cost(&Crucible::new(problem), START, backend)
+}
+
+/// The blocks a crucible passes through on a route losing the least heat, from the top left to
+/// the bottom right corner, or `None` if it can't get there.
+pub fn crucible_route(problem: &Problem) -> Option<Vec<Point<usize>>> {
+    let path = shortest_path(&Crucible::new(problem), START, Backend::Dial)?;
+    Some(
+        path.states
+            .iter()
+            .map(|node| Point::new(node.x, node.y))
+            .collect(),
+    )
+}
+
+/// The crucible in the top left corner, before it has moved.
+const START: NodeId = NodeId {
+    x: 0,
+    y: 0,
+    steps: 0,
+    direction: None,
+};
+
+/// The crucible's moves as a graph to search, with the least heat lost in any block to estimate
+/// the rest of the route for A*.
+struct Crucible<'a> {
+    problem: &'a Problem<'a>,
+    min_heat: usize,
+}
+
+impl<'a> Crucible<'a> {
+    fn new(problem: &'a Problem<'a>) -> Self {
+        Crucible {
+            problem,
+            min_heat: problem.grid.iter().copied().min().unwrap_or(0),
+        }
+    }
+}
+
+impl StateSpace for Crucible<'_> {
+    type State = NodeId;
+
+    fn size(&self) -> usize {
+        // The start, which has no direction, comes after every other node.
+        self.problem.max_index() + 1
+    }
+
+    fn index(&self, node: &NodeId) -> usize {
+        match node.direction {
+            Some(_) => self.problem.node_to_index(node),
+            None => self.problem.max_index(),
+        }
+    }
+
+    fn neighbors(&self, node: &NodeId) -> impl Iterator<Item = NodeId> {
+        node_neighbors(
+            *node,
+            self.problem.grid.rows(),
+            self.problem.grid.cols(),
+            self.problem.min_steps,
+            self.problem.max_steps,
+        )
+    }
+
+    fn cost(&self, _from: &NodeId, to: &NodeId) -> usize {
+        *self.problem.grid.get(to.y, to.x).unwrap()
+    }
+
+    fn is_goal(&self, node: &NodeId) -> bool {
+        node.x == self.problem.grid.cols() - 1
+            && node.y == self.problem.grid.rows() - 1
+            && node.steps >= self.problem.min_steps
+    }
+
+    fn heuristic(&self, node: &NodeId) -> usize {
+        let distance =
+            (self.problem.grid.cols() - 1 - node.x) + (self.problem.grid.rows() - 1 - node.y);
+        distance * self.min_heat
+    }
+}
+
+fn parse_input(input: &str) -> Result<Grid<usize>, ParseError> {
+    try_parse_grid(input, |value| {
+        value.to_digit(10).map(|d| d as usize).ok_or(BadChar {
+            found: value,
+            expected: "0123456789",
+        })
+    })
+}
+
+fn directed_steps(steps: usize, prev_dir: Option<Direction>, new_dir: Direction) -> usize {
+    if prev_dir == Some(new_dir) {
+        steps + 1
+    } else {
+        1
+    }
+}
+
+fn add_direction(id: NodeId, dir: Direction, rows: usize, cols: usize) -> Option<NodeId> {
+    Point::new(id.x, id.y).step(dir, cols, rows).map(|pos| {
+        NodeId::new(
+            pos.x,
+            pos.y,
+            directed_steps(id.steps, id.direction, dir),
+            Some(dir),
+        )
+    })
+}
+
+fn node_neighbors(
+    id: NodeId,
+    rows: usize,
+    cols: usize,
+    min_steps: usize,
+    max_steps: usize,
+) -> impl Iterator<Item = NodeId> {
+    Direction::ALL
+        .into_iter()
+        .filter_map(move |dir| add_direction(id, dir, rows, cols))
+        // can't turn around
+        .filter(move |neighbor| neighbor.direction != id.direction.map(Direction::opposite))
+        // must move at least min_steps in the same direction before turning
+        .filter(move |neighbor| {
+            id.steps >= min_steps || neighbor.direction == id.direction || id.direction.is_none()
+        })
+        // can't take more than max_steps steps in the same direction
+        .filter(move |neighbor| neighbor.steps <= max_steps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate::seeded;
+
+    #[test]
+    fn route_loses_the_least_heat() {
+        for seed in 0..20 {
+            let grid = parse_input(&seeded(generate::generate, seed, 12)).unwrap();
+            for (min_steps, max_steps) in [(1, 3), (4, 10)] {
+                let problem = Problem {
+                    grid: &grid,
+                    min_steps,
+                    max_steps,
+                };
+                let route = crucible_route(&problem).unwrap();
+                assert_eq!(route.first(), Some(&Point::new(0, 0)));
+                assert_eq!(
+                    route.last(),
+                    Some(&Point::new(grid.cols() - 1, grid.rows() - 1))
+                );
+                let heat: usize = route[1..].iter().map(|p| grid[(p.y, p.x)]).sum();
+                assert_eq!(Some(heat), crucible_walk(&problem), "seed {}", seed);
+            }
+        }
+    }
+
+    #[test]
+    fn unreachable_corner_has_no_answer() {
+        let grid = parse_input(&seeded(generate::generate, 0, 12)).unwrap();
+        let far = |min_steps, max_steps| {
+            let problem = Problem {
+                grid: &grid,
+                min_steps,
+                max_steps,
+            };
+            crucible_walk(&problem)
+        };
+        assert_eq!(far(20, 20), None);
+        assert_eq!(HeatLoss(None).answer(), None);
+
+        // Runs longer than the grid change nothing.
+        let params = |max_steps: &str| {
+            let given = [format!("max_steps={}", max_steps).parse().unwrap()];
+            Params::resolve(Day17::PARAMS, Part::One, &given).unwrap()
+        };
+        let huge = Problem::new(&grid, &params("1000"));
+        assert_eq!(huge.max_steps, grid.cols().max(grid.rows()));
+        assert_eq!(crucible_walk(&huge), far(1, 1000));
+        assert!(Params::resolve(
+            Day17::PARAMS,
+            Part::One,
+            &["max_steps=1001".parse().unwrap()]
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn backends_agree_with_petgraph() {
+        for seed in 0..20 {
+            let input = seeded(generate::generate, seed, 12);
+            let grid = parse_input(&input).unwrap();
+            let petgraph = old::parse_input(&input);
+            for (min_steps, max_steps, expected) in [
+                (1, 3, old::part_one(&petgraph)),
+                (4, 10, old::part_two(&petgraph)),
+            ] {
+                let problem = Problem {
+                    grid: &grid,
+                    min_steps,
+                    max_steps,
+                };
+                for backend in [Backend::Dial, Backend::Dijkstra, Backend::AStar] {
+                    let heat = crucible_search(&problem, backend);
+                    assert_eq!(heat, Some(expected as usize), "seed {} {:?}", seed, backend);
+                }
+            }
+        }
+    }
+}
@@ -1,8 +1,10 @@
-use grid::Grid;
+//! The first solution, which builds the whole state graph with petgraph and runs its Dijkstra.
+//! Kept as a baseline for the benchmarks.
+
+use aoc_common::Grid;
 use petgraph::algo::dijkstra;
 use petgraph::graphmap::GraphMap;
 use petgraph::Directed;
-use std::fs;
 
 #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
 enum Direction {
@@ -39,20 +41,13 @@ impl NodeId {
     }
 }
 
-fn main() {
-    let input_s = fs::read_to_string("inputs/day17.txt").unwrap();
-    let input = parse_input(&input_s);
-    println!("Part one: {}", part_one(&input));
-    println!("Part two: {}", part_two(&input));
-}
-
-fn part_one(input: &Grid<u8>) -> u32 {
-    let graph = build_graph(&input, 1, 3);
+pub fn part_one(input: &Grid<u8>) -> u32 {
+    let graph = build_graph(input, 1, 3);
     path_cost(input, &graph, 1, 3)
 }
 
-fn part_two(input: &Grid<u8>) -> u32 {
-    let graph = build_graph(&input, 4, 10);
+pub fn part_two(input: &Grid<u8>) -> u32 {
+    let graph = build_graph(input, 4, 10);
     path_cost(input, &graph, 4, 10)
 }
 
@@ -72,17 +67,17 @@ fn path_cost(
         .unwrap()
 }
 
-fn parse_input(input: &str) -> Grid<u8> {
+pub fn parse_input(input: &str) -> Grid<u8> {
     let input = input.trim();
-    let height = input.split("\n").count();
-    let width = input.split("\n").next().unwrap().len();
+    let height = input.split('\n').count();
+    let width = input.split('\n').next().unwrap().len();
     let mut grid = Grid::init(height, width, 0);
-    for (y, line) in input.split("\n").enumerate() {
+    for (y, line) in input.split('\n').enumerate() {
         for (x, value) in line.chars().enumerate() {
             *grid.get_mut(y, x).unwrap() = value.to_digit(10).unwrap() as u8;
         }
     }
-    return grid;
+    grid
 }
 
 fn all_dirsteps_iter(
@@ -184,5 +179,18 @@ fn build_graph(input: &Grid<u8>, min_steps: u8, max_steps: u8) -> GraphMap<NodeI
         );
     }
 
-    return graph;
+    graph
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn example() {
+        let input = parse_input(&fs::read_to_string("../examples/day17.txt").unwrap());
+        assert_eq!(part_one(&input), 102);
+        assert_eq!(part_two(&input), 94);
+    }
 }
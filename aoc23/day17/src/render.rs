@@ -0,0 +1,23 @@
+//! Drawing the city.
+
+use crate::{crucible_route, Day17, Problem};
+use anyhow::Result;
+use aoc_common::params::Params;
+use aoc_common::render::{Canvas, Color, Style};
+use aoc_common::Solution;
+use std::char;
+
+/// The heat loss of each block, from cool to hot, with part one's route, for its step limits
+/// in `params`, drawn on it.
+pub fn render(input: &str, params: &Params) -> Result<Canvas> {
+    let grid = Day17::parse(input)?;
+    let mut canvas = Canvas::new(&grid, |&heat| {
+        let glyph = char::from_digit(heat as u32, 10).unwrap_or('?');
+        Style::new(glyph, Color::BLUE.blend(Color::RED, heat as f64 / 9.0))
+    });
+    let problem = Problem::new(&grid, params);
+    if let Some(route) = crucible_route(&problem) {
+        canvas.path(route, Color::YELLOW);
+    }
+    Ok(canvas)
+}
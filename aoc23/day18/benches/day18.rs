@@ -0,0 +1,9 @@
+use aoc23_18::Day18;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn bench(c: &mut Criterion) {
+    aoc_common::bench::solution::<Day18>(c, 18);
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -0,0 +1,101 @@
+//! Random dig plans. `size` is roughly how many metres across the part one lagoon is.
+
+use aoc_common::generate::random_loop;
+use aoc_common::{Direction, Point};
+use rand::Rng;
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, HashMap};
+use std::ops::RangeInclusive;
+
+/// A plan whose trench never crosses itself. The colors encode the same trench with its
+/// corners spread much further apart, up to the five hex digits' limit, and sometimes
+/// turned a quarter.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let blocks = (size / 2).max(1);
+    let cells = random_loop(rng, blocks, blocks);
+    let corners: Vec<Point<usize>> = (0..cells.len())
+        .filter(|&i| {
+            let previous = cells[(i + cells.len() - 1) % cells.len()];
+            let next = cells[(i + 1) % cells.len()];
+            previous.x != next.x && previous.y != next.y
+        })
+        .map(|i| cells[i])
+        .collect();
+
+    let small = stretch(rng, &corners, 1..=3);
+    // There are at most 2 * blocks distinct xs or ys, so no leg can reach 0xFFFFF.
+    let large = stretch(rng, &corners, 1..=0xFFFFF / (4 * blocks));
+    let turned = rng.gen::<bool>();
+
+    let mut plan = String::new();
+    for i in 0..corners.len() {
+        let j = (i + 1) % corners.len();
+        let (direction, distance) = leg(small[i], small[j]);
+        let (mut big_direction, big_distance) = leg(large[i], large[j]);
+        if turned {
+            big_direction = big_direction.turn_right();
+        }
+        let code = match big_direction {
+            Direction::Right => 0,
+            Direction::Down => 1,
+            Direction::Left => 2,
+            Direction::Up => 3,
+        };
+        let letter = match direction {
+            Direction::Right => 'R',
+            Direction::Down => 'D',
+            Direction::Left => 'L',
+            Direction::Up => 'U',
+        };
+        plan.push_str(&format!(
+            "{} {} (#{:05x}{})\n",
+            letter, distance, big_distance, code
+        ));
+    }
+    plan
+}
+
+/// Move the corners apart by mapping each distinct x and y to a new one, keeping their order
+/// so that the trench still doesn't cross itself.
+fn stretch(
+    rng: &mut impl Rng,
+    corners: &[Point<usize>],
+    gaps: RangeInclusive<usize>,
+) -> Vec<Point<usize>> {
+    let mut axis = |values: BTreeSet<usize>| {
+        values
+            .into_iter()
+            .scan(0, |next, value| {
+                *next += rng.gen_range(gaps.clone());
+                Some((value, *next))
+            })
+            .collect::<HashMap<_, _>>()
+    };
+    let xs = axis(corners.iter().map(|p| p.x).collect());
+    let ys = axis(corners.iter().map(|p| p.y).collect());
+    corners
+        .iter()
+        .map(|p| Point::new(xs[&p.x], ys[&p.y]))
+        .collect()
+}
+
+fn leg(from: Point<usize>, to: Point<usize>) -> (Direction, usize) {
+    match (to.x.cmp(&from.x), to.y.cmp(&from.y)) {
+        (Ordering::Less, _) => (Direction::Left, from.x - to.x),
+        (Ordering::Greater, _) => (Direction::Right, to.x - from.x),
+        (_, Ordering::Less) => (Direction::Up, from.y - to.y),
+        _ => (Direction::Down, to.y - from.y),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day18;
+    use aoc_common::generate::exercise;
+
+    #[test]
+    fn generated_inputs_solve() {
+        exercise::<Day18>(generate, 20, 50);
+    }
+}
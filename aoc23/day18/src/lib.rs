@@ -0,0 +1,161 @@
+use anyhow::anyhow;
+use anyhow::Result;
+use aoc_common::parse::{lines, one_of, parse_all, tag, IResult};
+use aoc_common::{Direction, Point, Solution};
+use itertools::Itertools;
+use nom::{
+    bytes::complete::take_while_m_n,
+    character::complete::{i64 as nom_i64, line_ending, space1},
+    combinator::{map, map_res, opt},
+    error::context,
+    sequence::{delimited, pair, terminated, tuple},
+};
+
+#[cfg(any(test, feature = "generate"))]
+pub mod generate;
+
+fn parse_direction(value: char) -> anyhow::Result<Direction> {
+    match value {
+        // Part 1
+        'L' => Ok(Direction::Left),
+        'R' => Ok(Direction::Right),
+        'U' => Ok(Direction::Up),
+        'D' => Ok(Direction::Down),
+        // Part 2
+        '0' => Ok(Direction::Right),
+        '1' => Ok(Direction::Down),
+        '2' => Ok(Direction::Left),
+        '3' => Ok(Direction::Up),
+        _ => Err(anyhow!("Invalid direction: {}", value)),
+    }
+}
+
+pub struct Instruction {
+    pub distance: i64,
+    pub direction: Direction,
+}
+
+pub struct Input {
+    pub instructions: Vec<Instruction>,
+    /// The instructions decoded from the colors, for part two.
+    pub alternate_instructions: Vec<Instruction>,
+}
+
+/// A closed polygon, with the first point repeated at the end.
+pub struct Polygon {
+    pub points: Vec<Point<i64>>,
+}
+
+impl Polygon {
+    /// The enclosed area, by the shoelace formula.
+    pub fn area(&self) -> i64 {
+        self.points
+            .iter()
+            .tuple_windows()
+            .map(|(p1, p2)| p1.x * p2.y - p2.x * p1.y)
+            .sum::<i64>()
+            .abs()
+            / 2
+    }
+
+    pub fn perimeter(&self) -> i64 {
+        self.points
+            .iter()
+            .tuple_windows()
+            .map(|(p1, p2)| {
+                assert!(p1.x == p2.x || p1.y == p2.y);
+                ((p1.x - p2.x) + (p1.y - p2.y)).abs()
+            })
+            .sum::<i64>()
+    }
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Input;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_all(input, parse_input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        part_two(input)
+    }
+}
+
+fn part_one(input: &Input) -> i64 {
+    let polygon = follow_instructions(&input.instructions);
+    polygon.area() + (polygon.perimeter() / 2) + 1
+}
+
+fn part_two(input: &Input) -> i64 {
+    let polygon = follow_instructions(&input.alternate_instructions);
+    polygon.area() + (polygon.perimeter() / 2) + 1
+}
+
+/// The trench dug by following `instructions` from the origin.
+pub fn follow_instructions(instructions: &[Instruction]) -> Polygon {
+    let mut points = Vec::new();
+    let mut cur = Point::new(0, 0);
+    points.push(cur);
+    for instruction in instructions {
+        cur += instruction.direction.delta() * instruction.distance;
+        points.push(cur);
+    }
+    Polygon { points }
+}
+
+fn parse_input(input: &str) -> IResult<&str, Input> {
+    map(
+        terminated(lines(parse_instruction), opt(line_ending)),
+        |instruction_pairs| {
+            let (instructions, alternate_instructions) = instruction_pairs.into_iter().unzip();
+            Input {
+                instructions,
+                alternate_instructions,
+            }
+        },
+    )(input)
+}
+
+fn parse_instruction(input: &str) -> IResult<&str, (Instruction, Instruction)> {
+    map(
+        tuple((
+            terminated(map_res(one_of("LRUD"), parse_direction), space1),
+            terminated(nom_i64, space1),
+            delimited(tag("(#"), parse_alternate_instruction, tag(")")),
+        )),
+        |(direction, distance, alternate)| {
+            (
+                Instruction {
+                    direction,
+                    distance,
+                },
+                alternate,
+            )
+        },
+    )(input)
+}
+
+fn parse_alternate_instruction(input: &str) -> IResult<&str, Instruction> {
+    let hex_distance = context(
+        "5 hex digits",
+        map_res(take_while_m_n(5, 5, |c: char| c.is_ascii_hexdigit()), |s| {
+            i64::from_str_radix(s, 16)
+        }),
+    );
+    map(
+        pair(hex_distance, map_res(one_of("0123"), parse_direction)),
+        |(distance, direction)| Instruction {
+            direction,
+            distance,
+        },
+    )(input)
+}
@@ -0,0 +1,9 @@
+use aoc23_19::Day19;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn bench(c: &mut Criterion) {
+    aoc_common::bench::solution::<Day19>(c, 19);
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -0,0 +1,71 @@
+//! Random workflows and parts. `size` is the number of workflows and of parts.
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::HashSet;
+
+/// Workflows starting from `in`, each sending parts only to workflows listed after it or to
+/// `A` or `R`, so that every part is eventually accepted or rejected.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut names = vec!["in".to_string()];
+    let mut used: HashSet<String> = names.iter().cloned().collect();
+    while names.len() < size {
+        let name: String = (0..rng.gen_range(2..=3))
+            .map(|_| rng.gen_range('a'..='z'))
+            .collect();
+        if used.insert(name.clone()) {
+            names.push(name);
+        }
+    }
+
+    let mut system = String::new();
+    for (i, name) in names.iter().enumerate() {
+        let later = &names[i + 1..];
+        let mut rules: Vec<String> = (0..rng.gen_range(1..=4))
+            .map(|_| {
+                format!(
+                    "{}{}{}:{}",
+                    *b"xmas".choose(rng).unwrap() as char,
+                    if rng.gen() { '<' } else { '>' },
+                    rng.gen_range(1..4000),
+                    target(rng, later)
+                )
+            })
+            .collect();
+        rules.push(target(rng, later));
+        system.push_str(&format!("{}{{{}}}\n", name, rules.join(",")));
+    }
+
+    system.push('\n');
+    for _ in 0..size {
+        let ratings: Vec<String> = "xmas"
+            .chars()
+            .map(|property| format!("{}={}", property, rng.gen_range(1..=4000)))
+            .collect();
+        system.push_str(&format!("{{{}}}\n", ratings.join(",")));
+    }
+    system
+}
+
+fn target(rng: &mut impl Rng, later: &[String]) -> String {
+    if !later.is_empty() && rng.gen_bool(0.6) {
+        later.choose(rng).unwrap().clone()
+    } else if rng.gen() {
+        "A".to_string()
+    } else {
+        "R".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day19;
+    use aoc_common::generate::exercise;
+
+    #[test]
+    fn generated_inputs_solve() {
+        exercise::<Day19>(generate, 20, 50);
+    }
+}
@@ -0,0 +1,386 @@
+use anyhow::Result;
+use aoc_common::params::{Param, Params};
+use aoc_common::parse::{lines, parse_all, tag, BadChar, IResult};
+use aoc_common::Solution;
+use enum_map::{Enum, EnumMap};
+use nom::{
+    branch::alt,
+    character::complete::{alpha1, anychar, line_ending, multispace1, u16 as parse_u16},
+    combinator::{map, map_res},
+    multi::separated_list1,
+    sequence::{delimited, separated_pair, terminated, tuple},
+};
+use std::{collections::HashMap, ops::Range};
+
+#[cfg(any(test, feature = "generate"))]
+pub mod generate;
+pub mod reference;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Target {
+    Accept,
+    Reject,
+    Workflow { workflow: String },
+}
+
+#[derive(Debug, Enum, Clone, Copy, PartialEq, Eq)]
+pub enum Property {
+    ExtremeCoolness,
+    Musicality,
+    Aerodynamicity,
+    Shininess,
+}
+
+impl TryFrom<char> for Property {
+    type Error = BadChar;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'x' => Ok(Self::ExtremeCoolness),
+            'm' => Ok(Self::Musicality),
+            'a' => Ok(Self::Aerodynamicity),
+            's' => Ok(Self::Shininess),
+            found => Err(BadChar {
+                found,
+                expected: "xmas",
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparator {
+    Less,
+    Greater,
+}
+
+impl TryFrom<char> for Comparator {
+    type Error = BadChar;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '<' => Ok(Self::Less),
+            '>' => Ok(Self::Greater),
+            found => Err(BadChar {
+                found,
+                expected: "<>",
+            }),
+        }
+    }
+}
+
+impl Comparator {
+    pub fn matches<T>(&self, lhs: &T, rhs: &T) -> bool
+    where
+        T: PartialOrd,
+    {
+        match self {
+            Comparator::Less => lhs < rhs,
+            Comparator::Greater => lhs > rhs,
+        }
+    }
+
+    /// Split `range` into the values that pass the comparison with `quantity`, and those that
+    /// don't. Either may be empty.
+    pub fn split(&self, range: Range<u16>, quantity: u16) -> (Range<u16>, Range<u16>) {
+        match self {
+            Comparator::Less => {
+                let split = quantity.clamp(range.start, range.end);
+                let selected = range.start..split;
+                let remaining = split..range.end;
+                (selected, remaining)
+            }
+            Comparator::Greater => {
+                let split = (quantity + 1).clamp(range.start, range.end);
+                let selected = split..range.end;
+                let remaining = range.start..split;
+                (selected, remaining)
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Condition {
+    pub property: Property,
+    pub comparator: Comparator,
+    pub quantity: u16,
+}
+
+/// A workflow's rules, in order. The last has no condition.
+#[derive(Debug)]
+pub struct Workflow {
+    pub rules: Vec<(Option<Condition>, Target)>,
+}
+
+#[derive(Debug)]
+pub struct Part {
+    pub properties: EnumMap<Property, u16>,
+}
+
+impl Part {
+    pub fn score(&self) -> u64 {
+        self.properties.values().map(|&v| v as u64).sum::<u64>()
+    }
+}
+
+/// Every part with properties in these ranges.
+#[derive(Debug, Clone)]
+pub struct PartBin {
+    pub properties: EnumMap<Property, Range<u16>>,
+}
+
+impl PartBin {
+    /// Remove the parts that meet `condition` from this bin, and return them as a new one.
+    pub fn split(&mut self, condition: &Condition) -> PartBin {
+        let (split_range, remaining_range) = condition.comparator.split(
+            self.properties[condition.property].clone(),
+            condition.quantity,
+        );
+        let mut s = self.clone();
+        self.properties[condition.property] = remaining_range;
+        s.properties[condition.property] = split_range;
+        s
+    }
+
+    /// The number of parts in the bin.
+    pub fn score(&self) -> u64 {
+        self.properties
+            .values()
+            .map(|v| (v.end - v.start) as u64)
+            .product()
+    }
+}
+
+#[derive(Debug)]
+pub struct Input {
+    pub workflows: HashMap<String, Workflow>,
+    pub parts: Vec<Part>,
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Input;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    const PARAMS: &'static [Param] = &[
+        Param::new::<u16>("min_rating", "1", "Lowest rating of each category")
+            .part(aoc_common::Part::Two)
+            .no_more_than("max_rating"),
+        // The ratings are a range ending after the highest, which must fit in a u16 too.
+        Param::new::<u16>("max_rating", "4000", "Highest rating of each category")
+            .part(aoc_common::Part::Two)
+            .at_most(u16::MAX as i128 - 1),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_all(input, parse_input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        Self::part_two_with(
+            input,
+            &Params::defaults(Self::PARAMS, aoc_common::Part::Two),
+        )
+    }
+
+    fn part_two_with(input: &Self::Input, params: &Params) -> Self::PartTwo {
+        let max_rating: u16 = params.get("max_rating");
+        part_two(&input.workflows, params.get("min_rating")..max_rating + 1)
+    }
+}
+
+fn part_one(input: &Input) -> u64 {
+    input
+        .parts
+        .iter()
+        .filter(|part| destination(&input.workflows, part) == &Target::Accept)
+        .map(|part| part.score())
+        .sum::<u64>()
+}
+
+/// The number of combinations of ratings from `ratings` that are accepted.
+fn part_two(workflows: &HashMap<String, Workflow>, ratings: Range<u16>) -> u64 {
+    let initial = PartBin {
+        properties: EnumMap::from_fn(|_| ratings.clone()),
+    };
+
+    let accepted = apply_ranged_workflow(workflows, "in", &initial);
+
+    accepted.iter().map(|bin| bin.score()).sum::<u64>()
+}
+
+/// The parts of `bin` that are eventually accepted, starting from `workflow_name`.
+pub fn apply_ranged_workflow(
+    workflows: &HashMap<String, Workflow>,
+    workflow_name: &str,
+    bin: &PartBin,
+) -> Vec<PartBin> {
+    let workflow = workflows.get(workflow_name).unwrap();
+    let mut accepted = Vec::new();
+
+    let mut remaining = bin.clone();
+
+    for (maybe_condition, target) in &workflow.rules {
+        if let Some(condition) = maybe_condition {
+            let affected = remaining.split(condition);
+            match target {
+                Target::Accept => accepted.push(affected),
+                Target::Reject => {}
+                Target::Workflow { workflow } => {
+                    accepted.append(&mut apply_ranged_workflow(workflows, workflow, &affected));
+                }
+            };
+        } else {
+            match target {
+                Target::Accept => accepted.push(remaining.clone()),
+                Target::Reject => {}
+                Target::Workflow { workflow } => {
+                    accepted.append(&mut apply_ranged_workflow(workflows, workflow, &remaining));
+                }
+            };
+        }
+    }
+
+    accepted
+}
+
+/// Where `part` ends up, starting from the `in` workflow.
+pub fn destination<'a>(workflows: &'a HashMap<String, Workflow>, part: &Part) -> &'a Target {
+    apply_workflow(workflows, "in", part)
+}
+
+pub fn apply_workflow<'a>(
+    workflows: &'a HashMap<String, Workflow>,
+    workflow_name: &str,
+    part: &Part,
+) -> &'a Target {
+    let workflow = workflows.get(workflow_name).unwrap();
+    for (maybe_condition, target) in &workflow.rules {
+        if let Some(condition) = maybe_condition {
+            if matches_condition(condition, part) {
+                return match target {
+                    Target::Accept | Target::Reject => target,
+                    Target::Workflow { workflow } => apply_workflow(workflows, workflow, part),
+                };
+            }
+        } else {
+            return match target {
+                Target::Accept | Target::Reject => target,
+                Target::Workflow { workflow } => apply_workflow(workflows, workflow, part),
+            };
+        }
+    }
+    unreachable!();
+}
+
+fn matches_condition(condition: &Condition, part: &Part) -> bool {
+    condition
+        .comparator
+        .matches(&part.properties[condition.property], &condition.quantity)
+}
+
+fn parse_input(input: &str) -> IResult<&str, Input> {
+    map(
+        terminated(
+            separated_pair(parse_workflows, multispace1, parse_parts),
+            line_ending,
+        ),
+        |(rules, parts)| Input {
+            workflows: rules,
+            parts,
+        },
+    )(input)
+}
+
+fn parse_workflows(input: &str) -> IResult<&str, HashMap<String, Workflow>> {
+    map(lines(parse_workflow), HashMap::from_iter)(input)
+}
+
+fn parse_workflow(input: &str) -> IResult<&str, (String, Workflow)> {
+    // hxf{s<2974:dd,s<3412:fp,m<3807:jt,rmt}
+    map(
+        tuple((
+            alpha1,
+            tag("{"),
+            separated_list1(tag(","), parse_rule),
+            tag("}"),
+        )),
+        |(name, _, parts, _)| (name.to_owned(), Workflow { rules: parts }),
+    )(input)
+}
+
+fn parse_rule(input: &str) -> IResult<&str, (Option<Condition>, Target)> {
+    alt((parse_normal_rule, parse_default_rule))(input)
+}
+
+fn parse_normal_rule(input: &str) -> IResult<&str, (Option<Condition>, Target)> {
+    map(
+        tuple((
+            parse_property,
+            parse_comparator,
+            parse_u16,
+            tag(":"),
+            parse_target,
+        )),
+        |(property, comparator, quantity, _, target)| {
+            (
+                Some(Condition {
+                    property,
+                    comparator,
+                    quantity,
+                }),
+                target,
+            )
+        },
+    )(input)
+}
+
+fn parse_property(input: &str) -> IResult<&str, Property> {
+    map_res(anychar, Property::try_from)(input)
+}
+
+fn parse_comparator(input: &str) -> IResult<&str, Comparator> {
+    map_res(anychar, Comparator::try_from)(input)
+}
+
+fn parse_default_rule(input: &str) -> IResult<&str, (Option<Condition>, Target)> {
+    map(parse_target, |target| (None, target))(input)
+}
+
+fn parse_target(input: &str) -> IResult<&str, Target> {
+    alt((
+        map(tag("A"), |_| Target::Accept),
+        map(tag("R"), |_| Target::Reject),
+        map(alpha1, |workflow: &str| Target::Workflow {
+            workflow: workflow.to_owned(),
+        }),
+    ))(input)
+}
+
+fn parse_parts(input: &str) -> IResult<&str, Vec<Part>> {
+    lines(parse_part)(input)
+}
+
+fn parse_part(input: &str) -> IResult<&str, Part> {
+    // {x=653,m=2123,a=2908,s=577}
+    map(
+        delimited(
+            tag("{"),
+            separated_list1(
+                tag(","),
+                separated_pair(parse_property, tag("="), parse_u16),
+            ),
+            tag("}"),
+        ),
+        |props: Vec<(Property, u16)>| Part {
+            properties: EnumMap::from_iter(props),
+        },
+    )(input)
+}
@@ -0,0 +1,73 @@
+//! A naive reference solver, to check the range splitting against.
+
+use crate::{destination, Part, Property, Target, Workflow};
+use enum_map::EnumMap;
+use std::collections::{BTreeSet, HashMap};
+
+/// The number of distinct parts, with each property from 1 to 4000, that are accepted.
+///
+/// Parts only differ in which rules they meet where some rule's quantity falls between them,
+/// so this sends one part from each run of values between those quantities through the
+/// workflows, one at a time, and counts the runs it stands for.
+pub fn accepted_combinations(workflows: &HashMap<String, Workflow>) -> u64 {
+    // The first value of each run, per property.
+    let mut starts: EnumMap<Property, BTreeSet<u16>> = EnumMap::from_fn(|_| BTreeSet::from([1]));
+    for workflow in workflows.values() {
+        for (condition, _) in &workflow.rules {
+            if let Some(condition) = condition {
+                let start = &mut starts[condition.property];
+                start.insert(condition.quantity);
+                start.insert(condition.quantity + 1);
+            }
+        }
+    }
+    let runs: Vec<Vec<(u16, u64)>> = starts
+        .values()
+        .map(|starts| {
+            let starts: Vec<u16> = starts.iter().copied().filter(|&v| v <= 4000).collect();
+            starts
+                .iter()
+                .zip(starts.iter().skip(1).chain(Some(&4001)))
+                .map(|(&start, &end)| (start, (end - start) as u64))
+                .collect()
+        })
+        .collect();
+
+    let mut accepted = 0;
+    for &(x, x_len) in &runs[0] {
+        for &(m, m_len) in &runs[1] {
+            for &(a, a_len) in &runs[2] {
+                for &(s, s_len) in &runs[3] {
+                    let part = Part {
+                        properties: EnumMap::from_array([x, m, a, s]),
+                    };
+                    if destination(workflows, &part) == &Target::Accept {
+                        accepted += x_len * m_len * a_len * s_len;
+                    }
+                }
+            }
+        }
+    }
+    accepted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::generate;
+    use crate::Day19;
+    use aoc_common::generate::seeded;
+    use aoc_common::Solution;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn agrees_with_part_two(seed: u64, size in 1..8usize) {
+            let input = Day19::parse(&seeded(generate, seed, size)).unwrap();
+            prop_assert_eq!(
+                Day19::part_two(&input),
+                accepted_combinations(&input.workflows)
+            );
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use aoc23_2::Day2;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn bench(c: &mut Criterion) {
+    aoc_common::bench::solution::<Day2>(c, 2);
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
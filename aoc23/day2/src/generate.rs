@@ -0,0 +1,36 @@
+//! Random game records. `size` is the number of games.
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Games of one to six draws, each of one to three colors, with up to 20 cubes of a color.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let mut record = String::new();
+    for id in 1..=size.max(1) {
+        let draws: Vec<String> = (0..rng.gen_range(1..=6))
+            .map(|_| {
+                let mut colors = ["red", "green", "blue"];
+                colors.shuffle(rng);
+                colors[..rng.gen_range(1..=3)]
+                    .iter()
+                    .map(|color| format!("{} {}", rng.gen_range(1..=20), color))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect();
+        record.push_str(&format!("Game {}: {}\n", id, draws.join("; ")));
+    }
+    record
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day2;
+    use aoc_common::generate::exercise;
+
+    #[test]
+    fn generated_inputs_solve() {
+        exercise::<Day2>(generate, 20, 50);
+    }
+}
@@ -0,0 +1,397 @@
+use anyhow::{anyhow, Error, Result};
+use aoc_common::params::{Param, Params};
+use aoc_common::parse::{lines, parse_all, tag, IResult};
+use aoc_common::{Part, Solution};
+use std::cmp::max;
+use std::str::FromStr;
+
+use nom::character::complete::{alpha1, line_ending, space1, u32 as nom32};
+use nom::combinator::{all_consuming, cut, map, opt};
+use nom::error::context;
+use nom::multi::separated_list1;
+use nom::sequence::{pair, separated_pair, terminated, tuple};
+
+#[cfg(any(test, feature = "generate"))]
+pub mod generate;
+
+/// A color of cube, standing for its name in the record's [`Colors`].
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone)]
+pub struct Color(usize);
+
+/// The colors of cube in a record, each interned as a [`Color`] in the order it's first seen.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Colors {
+    names: Vec<String>,
+}
+
+impl Colors {
+    /// The color called `name`, added if it hasn't been seen before.
+    pub fn intern(&mut self, name: &str) -> Color {
+        match self.get(name) {
+            Some(color) => color,
+            None => {
+                self.names.push(name.to_string());
+                Color(self.names.len() - 1)
+            }
+        }
+    }
+
+    /// The color called `name`, if it's been seen.
+    pub fn get(&self, name: &str) -> Option<Color> {
+        self.names.iter().position(|n| n == name).map(Color)
+    }
+
+    pub fn name(&self, color: Color) -> &str {
+        &self.names[color.0]
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+}
+
+/// A number of cubes of each color: a handful drawn from the bag, or what's in the bag.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Draw {
+    /// The number of each color, by [`Color`]. Colors past the end have none.
+    pub counts: Vec<u32>,
+}
+
+impl Draw {
+    /// The number of cubes of `color`.
+    pub fn count(&self, color: Color) -> u32 {
+        self.counts.get(color.0).copied().unwrap_or(0)
+    }
+
+    /// Whether `other` could have been drawn from a bag holding this many cubes.
+    pub fn contains(&self, other: &Draw) -> bool {
+        (0..other.counts.len()).all(|c| other.count(Color(c)) <= self.count(Color(c)))
+    }
+
+    /// The fewest cubes of each color that could have produced both draws.
+    pub fn superset(&self, other: &Draw) -> Draw {
+        let colors = max(self.counts.len(), other.counts.len());
+        Draw {
+            counts: (0..colors)
+                .map(|c| max(self.count(Color(c)), other.count(Color(c))))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Game {
+    pub id: u32,
+    pub draws: Vec<Draw>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Input {
+    pub colors: Colors,
+    pub games: Vec<Game>,
+}
+
+impl Input {
+    /// The games as written, with their colors interned. A color given twice in a draw counts
+    /// the cubes of both.
+    fn new(games: Vec<(u32, Vec<RawDraw>)>) -> Input {
+        let mut colors = Colors::default();
+        let mut games: Vec<Game> = games
+            .into_iter()
+            .map(|(id, draws)| Game {
+                id,
+                draws: draws
+                    .into_iter()
+                    .map(|draw| {
+                        let mut counts = Vec::new();
+                        for (name, n) in draw {
+                            let Color(c) = colors.intern(name);
+                            if counts.len() <= c {
+                                counts.resize(c + 1, 0);
+                            }
+                            counts[c] += n;
+                        }
+                        Draw { counts }
+                    })
+                    .collect(),
+            })
+            .collect();
+        // Give every draw a count of every color, so that they're all alike.
+        for draw in games.iter_mut().flat_map(|game| &mut game.draws) {
+            draw.counts.resize(colors.len(), 0);
+        }
+        Input { colors, games }
+    }
+}
+
+/// The cubes in the bag for part one, by color name, parsed from a list like
+/// `red=12,green=13,blue=14`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bag {
+    pub cubes: Vec<(String, u32)>,
+}
+
+impl Bag {
+    /// The cubes in the bag as a draw of `colors`. Colors that aren't in the bag have none, and
+    /// colors given twice have both counts.
+    pub fn draw(&self, colors: &Colors) -> Draw {
+        let mut counts = vec![0; colors.len()];
+        for (name, n) in &self.cubes {
+            if let Some(Color(c)) = colors.get(name) {
+                counts[c] += *n;
+            }
+        }
+        Draw { counts }
+    }
+}
+
+impl FromStr for Bag {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Bag> {
+        let cubes = s
+            .split(',')
+            .map(|cubes| {
+                let (name, n) = cubes
+                    .split_once('=')
+                    .ok_or_else(|| anyhow!("expected COLOR=CUBES, found {}", cubes))?;
+                Ok((name.trim().to_string(), n.trim().parse()?))
+            })
+            .collect::<Result<_>>()?;
+        Ok(Bag { cubes })
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Input;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    const PARAMS: &'static [Param] = &[Param::new::<Bag>(
+        "bag",
+        "red=12,green=13,blue=14",
+        "Cubes of each color in the bag",
+    )
+    .part(Part::One)];
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_all(input, parse_input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        Self::part_one_with(input, &Params::defaults(Self::PARAMS, Part::One))
+    }
+
+    fn part_one_with(input: &Self::Input, params: &Params) -> Self::PartOne {
+        let bag: Bag = params.get("bag");
+        part_one(input, &bag.draw(&input.colors))
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        part_two(input)
+    }
+}
+
+/// The sum of the IDs of the games that could have been played with `bag`.
+fn part_one(input: &Input, bag: &Draw) -> u32 {
+    input
+        .games
+        .iter()
+        .filter(|g| g.draws.iter().all(|d| bag.contains(d)))
+        .map(|g| g.id)
+        .sum()
+}
+
+/// The sum over the games of the product of the fewest cubes of each color that could have
+/// been in the bag. A game that never shows a color of the record has a product of 0.
+fn part_two(input: &Input) -> u32 {
+    input
+        .games
+        .iter()
+        .map(|g| {
+            g.draws
+                .iter()
+                .fold(Draw::default(), |acc, e| acc.superset(e))
+        })
+        .map(|d| d.counts.iter().product::<u32>())
+        .sum()
+}
+
+/// A draw as written, before its colors are interned.
+type RawDraw<'a> = Vec<(&'a str, u32)>;
+
+fn color(input: &str) -> IResult<&str, &str> {
+    context("a color", alpha1)(input)
+}
+
+fn draw_item(input: &str) -> IResult<&str, (&str, u32)> {
+    map(separated_pair(nom32, space1, color), |(n, c)| (c, n))(input)
+}
+
+fn draw(input: &str) -> IResult<&str, RawDraw<'_>> {
+    separated_list1(tag(", "), cut(draw_item))(input)
+}
+
+fn draws(input: &str) -> IResult<&str, Vec<RawDraw<'_>>> {
+    separated_list1(tag("; "), draw)(input)
+}
+
+fn header(input: &str) -> IResult<&str, u32> {
+    map(tuple((tag("Game"), space1, nom32, tag(":"), space1)), |r| {
+        r.2
+    })(input)
+}
+
+fn game(input: &str) -> IResult<&str, (u32, Vec<RawDraw<'_>>)> {
+    pair(header, draws)(input)
+}
+
+fn parse_input(input: &str) -> IResult<&str, Input> {
+    all_consuming(map(terminated(lines(game), opt(line_ending)), Input::new))(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn parse_color() {
+        assert_eq!(color("green"), Ok(("", "green")))
+    }
+
+    #[test]
+    fn parse_draw() {
+        assert_eq!(draw("3 green"), Ok(("", vec![("green", 3)])))
+    }
+
+    #[test]
+    fn parse_draw_multi() {
+        assert_eq!(
+            draw("3 green, 1 blue"),
+            Ok(("", vec![("green", 3), ("blue", 1)]))
+        )
+    }
+
+    #[test]
+    fn parse_draws() {
+        assert_eq!(
+            draws("3 green; 1 red"),
+            Ok(("", vec![vec![("green", 3)], vec![("red", 1)]]))
+        )
+    }
+
+    #[test]
+    fn parse_draws_multi() {
+        assert_eq!(
+            draws("3 green, 1 blue; 1 red, 2 green"),
+            Ok((
+                "",
+                vec![
+                    vec![("green", 3), ("blue", 1)],
+                    vec![("red", 1), ("green", 2)]
+                ]
+            ))
+        )
+    }
+
+    #[test]
+    fn parse_header() {
+        assert_eq!(header("Game 4: "), Ok(("", 4)))
+    }
+
+    #[test]
+    fn parse_game() {
+        let s = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green";
+        let g = game(s);
+        assert!(g.is_ok());
+        assert_eq!(g.unwrap().0, "");
+    }
+
+    #[test]
+    fn parse_error() {
+        let s = "Game 1: 3 blue, 4 red\nGame 2: 1 red; 2 3\n";
+        assert_eq!(
+            parse_all(s, parse_input).unwrap_err().to_string(),
+            "line 2, column 18: expected a color, found \"3\""
+        );
+    }
+
+    #[test]
+    fn colors_are_interned() {
+        let input =
+            Day2::parse("Game 1: 3 yellow; 2 purple, 1 yellow\nGame 2: 4 purple\n").unwrap();
+        let yellow = input.colors.get("yellow").unwrap();
+        let purple = input.colors.get("purple").unwrap();
+        assert_eq!((yellow, purple), (Color(0), Color(1)));
+        assert_eq!(input.colors.name(purple), "purple");
+        assert_eq!(input.games[0].draws[0], Draw { counts: vec![3, 0] });
+        assert_eq!(input.games[1].draws[0], Draw { counts: vec![0, 4] });
+    }
+
+    #[test]
+    fn repeated_colors_add_up() {
+        let input = Day2::parse(
+            "Game 1: 3 red, 2 blue, 4 red; 1 blue
+",
+        )
+        .unwrap();
+        assert_eq!(input.games[0].draws[0], Draw { counts: vec![7, 2] });
+        assert_eq!(part_two(&input), 7 * 2);
+        let bag: Bag = "red=5,blue=2,red=2".parse().unwrap();
+        assert_eq!(bag.draw(&input.colors), Draw { counts: vec![7, 2] });
+        assert_eq!(part_one(&input, &bag.draw(&input.colors)), 1);
+    }
+
+    #[test]
+    fn any_colors() {
+        let input = Day2::parse(
+            "Game 1: 3 yellow; 2 purple\nGame 2: 4 purple, 1 yellow\nGame 3: 5 purple\n",
+        )
+        .unwrap();
+        let bag: Bag = "purple=4, yellow=3, red=1".parse().unwrap();
+        assert_eq!(part_one(&input, &bag.draw(&input.colors)), 3);
+        // The third game has no yellow.
+        assert_eq!(part_two(&input), 6 + 4);
+    }
+
+    #[test]
+    fn bags() {
+        assert_eq!(
+            "red=12,green=13".parse::<Bag>().unwrap(),
+            Bag {
+                cubes: vec![("red".to_string(), 12), ("green".to_string(), 13)]
+            }
+        );
+        assert!("red".parse::<Bag>().is_err());
+        assert!("red=-1".parse::<Bag>().is_err());
+    }
+
+    #[test]
+    fn parse_example() {
+        let s = fs::read_to_string("../examples/day2.txt").unwrap();
+        let g = parse_input(&s);
+        assert!(g.is_ok());
+        assert_eq!(g.unwrap().0, "");
+    }
+
+    #[test]
+    fn example_part_one() {
+        let s = fs::read_to_string("../examples/day2.txt").unwrap();
+        let (_, i) = parse_input(&s).unwrap();
+        assert_eq!(Day2::part_one(&i), 8);
+    }
+
+    #[test]
+    fn example_part_two() {
+        let s = fs::read_to_string("../examples/day2.txt").unwrap();
+        let (_, i) = parse_input(&s).unwrap();
+        assert_eq!(part_two(&i), 2286);
+    }
+}
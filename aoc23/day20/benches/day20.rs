@@ -0,0 +1,9 @@
+use aoc23_20::Day20;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn bench(c: &mut Criterion) {
+    aoc_common::bench::solution::<Day20>(c, 20);
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
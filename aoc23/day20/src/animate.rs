@@ -0,0 +1,67 @@
+//! Animating the button presses.
+
+use crate::{execute, parse_circuit, Component, Input, Voltage};
+use anyhow::Result;
+use aoc_common::animate::Animation;
+use aoc_common::params::Params;
+use aoc_common::render::{Canvas, Color, Style};
+use aoc_common::{Grid, Point};
+use std::collections::HashSet;
+
+/// The state of every module after each of part one's button presses, as many as `params`
+/// says.
+///
+/// The modules are laid out in a square in name order: `%` flip-flops are lit while they're
+/// on, `&` conjunctions get greener as more of their inputs are remembered high, and
+/// conjunctions that sent a low pulse during the press are highlighted.
+pub fn animate(input: &str, params: &Params, animation: &mut Animation) -> Result<()> {
+    let mut components = parse_circuit(input)?;
+    let presses: usize = params.get("presses");
+    let mut names: Vec<String> = components.keys().cloned().collect();
+    names.sort_unstable();
+
+    animation.step(|| draw(&components, &names, &HashSet::new()))?;
+    for _ in 0..presses {
+        let mut sent_low = HashSet::new();
+        execute(&mut components, |pulse| {
+            if pulse.voltage == Voltage::Low {
+                sent_low.insert(pulse.source.clone());
+            }
+        });
+        animation.step(|| draw(&components, &names, &sent_low))?;
+    }
+    animation.finish(|| draw(&components, &names, &HashSet::new()))?;
+    Ok(())
+}
+
+fn draw(components: &Input, names: &[String], sent_low: &HashSet<String>) -> Canvas {
+    let cols = (names.len() as f64).sqrt().ceil().max(1.0) as usize;
+    let mut cells: Vec<Option<&Component>> =
+        names.iter().map(|name| components.get(name)).collect();
+    cells.resize(names.len().div_ceil(cols) * cols, None);
+    let grid = Grid::from_vec(cells, cols);
+
+    let mut canvas = Canvas::new(&grid, |component| match component {
+        None => Style::new(' ', Color::BLACK),
+        Some(Component::FlipFlop { on, .. }) => {
+            Style::new('%', if *on { Color::YELLOW } else { Color::DARK_GREY })
+        }
+        Some(Component::Conjunction { inputs, .. }) => {
+            let high = inputs.values().filter(|&&v| v == Voltage::High).count();
+            let color =
+                Color::DARK_GREY.blend(Color::GREEN, high as f64 / inputs.len().max(1) as f64);
+            Style::new('&', color)
+        }
+        Some(Component::Broadcast { .. }) => Style::new('b', Color::WHITE),
+        Some(Component::Output { .. }) => Style::new('o', Color::WHITE),
+    });
+    let sent_low = names.iter().enumerate().filter(|(_, name)| {
+        sent_low.contains(*name)
+            && matches!(components.get(*name), Some(Component::Conjunction { .. }))
+    });
+    canvas.highlight(
+        sent_low.map(|(i, _)| Point::new(i % cols, i / cols)),
+        Color::RED,
+    );
+    canvas
+}
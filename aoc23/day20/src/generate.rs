@@ -0,0 +1,71 @@
+//! Random module configurations. `size` is the number of counters, from one to five.
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::HashSet;
+
+const BITS: usize = 12;
+
+/// Configurations with the shape part two relies on: the broadcaster starts several 12-bit
+/// counters of flip-flops, each reset by a conjunction when it reaches a random period with its
+/// top and bottom bits set. Each reset is inverted and fed to one last conjunction before `rx`,
+/// which is then first sent a low pulse after the product of the periods.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let mut used = HashSet::from(["rx".to_string()]);
+    let mut lines = Vec::new();
+    let mut starts = Vec::new();
+    let last = name(rng, &mut used);
+    for _ in 0..size.clamp(1, 5) {
+        let period: usize = rng.gen_range(1 << (BITS - 1)..1 << BITS) | 1;
+        let flip_flops: Vec<String> = (0..BITS).map(|_| name(rng, &mut used)).collect();
+        let (hub, inverter) = (name(rng, &mut used), name(rng, &mut used));
+
+        let mut hub_outputs = vec![flip_flops[0].clone()];
+        for (bit, flip_flop) in flip_flops.iter().enumerate() {
+            let mut outputs: Vec<&str> = flip_flops
+                .get(bit + 1)
+                .map(String::as_str)
+                .into_iter()
+                .collect();
+            if period & (1 << bit) != 0 {
+                outputs.push(&hub);
+            } else {
+                hub_outputs.push(flip_flop.clone());
+            }
+            outputs.shuffle(rng);
+            lines.push(format!("%{} -> {}", flip_flop, outputs.join(", ")));
+        }
+        hub_outputs.push(inverter.clone());
+        hub_outputs.shuffle(rng);
+        lines.push(format!("&{} -> {}", hub, hub_outputs.join(", ")));
+        lines.push(format!("&{} -> {}", inverter, last));
+
+        starts.push(flip_flops[0].clone());
+    }
+    lines.push(format!("&{} -> rx", last));
+    lines.push(format!("broadcaster -> {}", starts.join(", ")));
+    lines.shuffle(rng);
+    lines.join("\n") + "\n"
+}
+
+/// A new two-letter name.
+fn name(rng: &mut impl Rng, used: &mut HashSet<String>) -> String {
+    loop {
+        let name: String = (0..2).map(|_| rng.gen_range('a'..='z')).collect();
+        if used.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day20;
+    use aoc_common::generate::exercise;
+
+    #[test]
+    fn generated_inputs_solve() {
+        exercise::<Day20>(generate, 3, 10);
+    }
+}
@@ -0,0 +1,403 @@
+use anyhow::Result;
+use aoc_common::params::{Param, Params};
+use aoc_common::parse::{lines, parse_all, tag, IResult};
+use aoc_common::{Part, Solution};
+use halfbrown::HashMap;
+use nom::{
+    branch::alt,
+    character::complete::{alpha1, line_ending},
+    combinator::{map, opt},
+    multi::separated_list1,
+    sequence::{preceded, separated_pair, terminated},
+};
+use num::integer::lcm;
+use std::{
+    collections::{HashSet, VecDeque},
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
+use tracing::{debug, trace};
+
+pub mod animate;
+#[cfg(any(test, feature = "generate"))]
+pub mod generate;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Voltage {
+    Low,
+    High,
+}
+
+#[derive(Debug, Clone)]
+pub struct Pulse {
+    pub voltage: Voltage,
+    pub source: String,
+    pub destination: String,
+}
+
+#[derive(Debug, Clone)]
+pub enum Component {
+    Broadcast {
+        name: String,
+        outputs: Vec<String>,
+    },
+    FlipFlop {
+        name: String,
+        on: bool,
+        outputs: Vec<String>,
+    },
+    Conjunction {
+        name: String,
+        inputs: HashMap<String, Voltage>,
+        outputs: Vec<String>,
+    },
+    Output {
+        name: String,
+        outputs: Vec<String>,
+    },
+}
+
+impl Component {
+    pub fn outputs(&self) -> &Vec<String> {
+        match self {
+            Component::Broadcast { outputs, .. } => outputs,
+            Component::FlipFlop { outputs, .. } => outputs,
+            Component::Conjunction { outputs, .. } => outputs,
+            Component::Output { outputs, .. } => outputs,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            Component::Broadcast { name, .. } => name,
+            Component::FlipFlop { name, .. } => name,
+            Component::Conjunction { name, .. } => name,
+            Component::Output { name, .. } => name,
+        }
+    }
+
+    /// Receive `input`, updating this component's state, and return the pulses it sends.
+    pub fn pulse(&mut self, input: &Pulse) -> Vec<Pulse> {
+        match self {
+            Component::Broadcast { .. } => self
+                .outputs()
+                .iter()
+                .map(|output| Pulse {
+                    voltage: input.voltage,
+                    source: self.name().to_owned(),
+                    destination: output.to_owned(),
+                })
+                .collect::<Vec<_>>(),
+            Component::FlipFlop { on, .. } => {
+                if input.voltage == Voltage::High {
+                    return Vec::new();
+                }
+                *on = !(*on);
+                let output_voltage = if *on { Voltage::High } else { Voltage::Low };
+                self.outputs()
+                    .iter()
+                    .map(|output| Pulse {
+                        voltage: output_voltage,
+                        source: self.name().to_owned(),
+                        destination: output.to_owned(),
+                    })
+                    .collect::<Vec<_>>()
+            }
+            Component::Conjunction { inputs, .. } => {
+                *inputs.get_mut(&input.source).unwrap() = input.voltage;
+                let output_voltage = if inputs.values().all(|v| *v == Voltage::High) {
+                    Voltage::Low
+                } else {
+                    Voltage::High
+                };
+                self.outputs()
+                    .iter()
+                    .map(|output| Pulse {
+                        voltage: output_voltage,
+                        source: self.name().to_owned(),
+                        destination: output.to_owned(),
+                    })
+                    .collect::<Vec<_>>()
+            }
+            Component::Output { .. } => Vec::new(),
+        }
+    }
+}
+
+pub type Input = HashMap<String, Component>;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Input;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    const PARAMS: &'static [Param] =
+        &[Param::new::<usize>("presses", "1000", "Times to push the button").part(Part::One)];
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_circuit(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        Self::part_one_with(input, &Params::defaults(Self::PARAMS, Part::One))
+    }
+
+    fn part_one_with(input: &Self::Input, params: &Params) -> Self::PartOne {
+        part_one(input, params.get("presses"))
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        part_two(input)
+    }
+}
+
+pub fn write_dotfile(filename: &str, input: &Input) -> Result<()> {
+    let path = Path::new(filename);
+    let mut file = BufWriter::new(File::create(path)?);
+    file.write_all("digraph Circuit {\n".as_bytes())?;
+    for (src, component) in input {
+        let shape = match component {
+            Component::Broadcast { .. } => "diamond",
+            Component::FlipFlop { .. } => "box",
+            Component::Conjunction { .. } => "oval",
+            Component::Output { .. } => "diamond",
+        };
+        file.write_all(format!("  {} [shape={}]\n", src, shape).as_bytes())?;
+    }
+    for (src, component) in input {
+        for dst in component.outputs() {
+            file.write_all(format!("  {} -> {}\n", src, dst).as_bytes())?;
+        }
+    }
+    file.write_all("}\n".as_bytes())?;
+    Ok(())
+}
+
+fn part_two(components: &Input) -> u64 {
+    let mut components = components.clone();
+    // Wired to this particular input:
+    // There is exactly one output node.
+    let output_node_name = components
+        .iter()
+        .find_map(|(_, component)| match component {
+            Component::Output { name, .. } => Some(name.to_owned()),
+            _ => None,
+        })
+        .unwrap();
+
+    // The output node is fed by a single combiner, whose only output is to the output node.
+    let combiner_node_name = components
+        .iter()
+        .find_map(|(name, component)| {
+            if component.outputs().contains(&output_node_name) {
+                Some(name.to_owned())
+            } else {
+                None
+            }
+        })
+        .unwrap();
+
+    let Component::Conjunction {
+        inputs: combiner_node_inputs,
+        ..
+    } = components.get(&combiner_node_name).unwrap()
+    else {
+        panic!("broken assumption");
+    };
+    let inputs = combiner_node_inputs
+        .keys()
+        .map(|s| s.to_owned())
+        .collect::<Vec<_>>();
+
+    // Find the cycle length of each combiner's input.
+    // Assumes that each input is cyclic with an offset of zero.
+    let pulse_times = execute_watching(&mut components, &inputs);
+    pulse_times.values().cloned().reduce(lcm).unwrap()
+}
+
+/// The number of high pulses times the number of low pulses sent by pushing the button
+/// `presses` times.
+fn part_one(components: &Input, presses: usize) -> u64 {
+    let mut components = components.clone();
+    let mut sum_high = 0;
+    let mut sum_low = 0;
+    for press in 1..=presses {
+        let (high, low) = execute_counting(&mut components);
+        trace!(press, high, low, "pulses");
+        sum_high += high;
+        sum_low += low;
+    }
+    sum_high * sum_low
+}
+
+/// Press the button until every component in `watch` has sent a high pulse, returning the
+/// number of presses it took for each.
+pub fn execute_watching(components: &mut Input, watch: &[String]) -> HashMap<String, u64> {
+    let mut values = HashMap::new();
+    let mut button_presses = 0;
+
+    while values.len() != watch.len() {
+        button_presses += 1;
+        let mut pulses = 0;
+        execute(components, |pulse| {
+            pulses += 1;
+            if watch.contains(&pulse.source)
+                && !values.contains_key(&pulse.source)
+                && pulse.voltage == Voltage::High
+            {
+                debug!(source = pulse.source, press = button_presses, "sent high");
+                values.insert(pulse.source.to_owned(), button_presses);
+            }
+        });
+        trace!(press = button_presses, pulses, "pulses");
+    }
+
+    values
+}
+
+/// Press the button once, returning the number of high and low pulses sent.
+pub fn execute_counting(components: &mut Input) -> (u64, u64) {
+    let mut count_high = 0;
+    let mut count_low = 0; // 1
+
+    execute(components, |pulse| match pulse.voltage {
+        Voltage::Low => count_low += 1,
+        Voltage::High => count_high += 1,
+    });
+    (count_high, count_low)
+}
+
+/// Press the button once and deliver every pulse until the circuit settles, passing each pulse
+/// to `callback` as it is sent.
+pub fn execute<CB>(components: &mut Input, mut callback: CB)
+where
+    CB: FnMut(&Pulse),
+{
+    let mut queue = VecDeque::new();
+
+    let start_pulse = Pulse {
+        voltage: Voltage::Low,
+        source: "button".to_owned(),
+        destination: "broadcaster".to_owned(),
+    };
+    callback(&start_pulse);
+    queue.push_back(start_pulse);
+
+    while let Some(pulse) = queue.pop_front() {
+        let component = components
+            .get_mut(&pulse.destination)
+            .unwrap_or_else(|| panic!("Component {} not found", pulse.destination));
+        let generated_pulses = component.pulse(&pulse);
+
+        for pulse in &generated_pulses {
+            callback(pulse);
+        }
+
+        queue.append(&mut VecDeque::from(generated_pulses));
+    }
+}
+
+pub fn parse_circuit(input: &str) -> Result<Input> {
+    let mut circuit = parse_all(input, parse_input)?;
+    create_outputs(&mut circuit);
+    wire_conjunctions(&mut circuit);
+    Ok(circuit)
+}
+
+fn create_outputs(input: &mut Input) {
+    let outputs = input
+        .iter()
+        .flat_map(|(_, comp)| comp.outputs().iter())
+        .map(|s| s.to_owned())
+        .collect::<HashSet<_>>();
+    for output in outputs {
+        if input.get(&output).is_none() {
+            input.insert(
+                output.clone(),
+                Component::Output {
+                    name: output.clone(),
+                    outputs: Vec::new(),
+                },
+            );
+        }
+    }
+}
+
+fn wire_conjunctions(input: &mut Input) {
+    let pairs = input
+        .iter()
+        .flat_map(|(src, comp)| comp.outputs().iter().map(move |dest| (src, dest)))
+        .map(|(src, dest)| (src.to_owned(), dest.to_owned()))
+        .collect::<Vec<_>>();
+
+    for (src, dest) in pairs {
+        if let Some(Component::Conjunction { inputs: state, .. }) = input.get_mut(&dest) {
+            state.insert(src.clone(), Voltage::Low);
+        }
+    }
+}
+
+fn parse_input(input: &str) -> IResult<&str, Input> {
+    map(
+        terminated(lines(parse_component), opt(line_ending)),
+        HashMap::from_iter,
+    )(input)
+}
+
+fn parse_component(input: &str) -> IResult<&str, (String, Component)> {
+    alt((parse_broadcast, parse_flipflop, parse_conjunction))(input)
+}
+
+fn parse_broadcast(input: &str) -> IResult<&str, (String, Component)> {
+    map(
+        preceded(tag("broadcaster -> "), parse_output_list),
+        |outputs| {
+            (
+                "broadcaster".to_owned(),
+                Component::Broadcast {
+                    name: "broadcaster".to_owned(),
+                    outputs,
+                },
+            )
+        },
+    )(input)
+}
+
+fn parse_flipflop(input: &str) -> IResult<&str, (String, Component)> {
+    map(
+        separated_pair(preceded(tag("%"), alpha1), tag(" -> "), parse_output_list),
+        |(name, outputs)| {
+            (
+                name.to_owned(),
+                Component::FlipFlop {
+                    name: name.to_owned(),
+                    on: false,
+                    outputs,
+                },
+            )
+        },
+    )(input)
+}
+
+fn parse_conjunction(input: &str) -> IResult<&str, (String, Component)> {
+    map(
+        separated_pair(preceded(tag("&"), alpha1), tag(" -> "), parse_output_list),
+        |(name, outputs)| {
+            (
+                name.to_owned(),
+                Component::Conjunction {
+                    name: name.to_owned(),
+                    inputs: HashMap::new(),
+                    outputs,
+                },
+            )
+        },
+    )(input)
+}
+
+fn parse_output_list(input: &str) -> IResult<&str, Vec<String>> {
+    separated_list1(tag(", "), map(alpha1, |s: &str| s.to_owned()))(input)
+}
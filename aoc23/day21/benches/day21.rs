@@ -0,0 +1,9 @@
+use aoc23_21::Day21;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn bench(c: &mut Criterion) {
+    aoc_common::bench::solution::<Day21>(c, 21);
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -0,0 +1,23 @@
+//! Animating the elf's steps.
+
+use crate::render::draw;
+use crate::{step, Day21};
+use anyhow::Result;
+use aoc_common::animate::Animation;
+use aoc_common::params::Params;
+use aoc_common::Solution;
+use std::collections::HashSet;
+
+/// The plots the elf could be on after each of part one's steps, as many as `params` says.
+pub fn animate(input: &str, params: &Params, animation: &mut Animation) -> Result<()> {
+    let garden = Day21::parse(input)?;
+    let steps: usize = params.get("steps");
+    let mut plots = HashSet::from([garden.start]);
+    animation.step(|| draw(&garden, &plots))?;
+    for _ in 0..steps {
+        plots = step(&garden, plots);
+        animation.step(|| draw(&garden, &plots))?;
+    }
+    animation.finish(|| draw(&garden, &plots))?;
+    Ok(())
+}
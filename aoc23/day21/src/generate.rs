@@ -0,0 +1,35 @@
+//! Random garden maps. `size` is roughly the width and height of the map.
+
+use rand::Rng;
+
+/// An odd-sized square map with the start in the middle and 10 to 20% of it rock, except on the
+/// start's row and column, which are clear as in the real input.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let size = size / 2 * 2 + 1;
+    let middle = size / 2;
+    let density = rng.gen_range(0.1..0.2);
+    (0..size)
+        .map(|row| {
+            (0..size)
+                .map(|col| match (row == middle, col == middle) {
+                    (true, true) => 'S',
+                    (false, false) if rng.gen_bool(density) => '#',
+                    _ => '.',
+                })
+                .chain(Some('\n'))
+                .collect::<String>()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day21;
+    use aoc_common::generate::exercise;
+
+    #[test]
+    fn generated_inputs_solve() {
+        exercise::<Day21>(generate, 20, 20);
+    }
+}
@@ -0,0 +1,110 @@
+use std::collections::HashSet;
+
+use anyhow::{anyhow, Result};
+use aoc_common::grid::{position, try_parse_grid};
+use aoc_common::params::{Param, Params};
+use aoc_common::parse::BadChar;
+use aoc_common::{Grid, Part, Point, Solution, Unsolved};
+use tracing::trace;
+
+pub mod animate;
+#[cfg(any(test, feature = "generate"))]
+pub mod generate;
+pub mod render;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Plot {
+    Garden,
+    Rock,
+    Start,
+}
+
+impl TryFrom<char> for Plot {
+    type Error = BadChar;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Plot::Garden),
+            '#' => Ok(Plot::Rock),
+            'S' => Ok(Plot::Start),
+            found => Err(BadChar {
+                found,
+                expected: ".#S",
+            }),
+        }
+    }
+}
+
+pub type Location = Point<usize>;
+
+pub struct Input {
+    pub grid: Grid<Plot>,
+    pub start: Location,
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Input;
+    type PartOne = usize;
+    type PartTwo = Unsolved;
+
+    const PARAMS: &'static [Param] =
+        &[Param::new::<usize>("steps", "64", "Steps the elf takes").part(Part::One)];
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        Self::part_one_with(input, &Params::defaults(Self::PARAMS, Part::One))
+    }
+
+    fn part_one_with(input: &Self::Input, params: &Params) -> Self::PartOne {
+        reachable(input, params.get("steps"))
+    }
+
+    fn part_two(_input: &Self::Input) -> Self::PartTwo {
+        Unsolved
+    }
+}
+
+/// The number of garden plots that can be reached in `steps` steps.
+pub fn reachable(input: &Input, steps: usize) -> usize {
+    reachable_plots(input, steps).len()
+}
+
+/// The garden plots that can be reached in `steps` steps.
+pub fn reachable_plots(input: &Input, steps: usize) -> HashSet<Location> {
+    let mut locations = HashSet::new();
+
+    locations.insert(input.start);
+
+    for i in 1..=steps {
+        locations = step(input, locations);
+        trace!(step = i, plots = locations.len(), "stepped");
+    }
+
+    locations
+}
+
+/// The garden plots that can be reached in one step from any of `locations`.
+pub fn step(input: &Input, mut locations: HashSet<Location>) -> HashSet<Location> {
+    let mut new_locs = HashSet::new();
+    for loc in locations.drain() {
+        new_locs.extend(
+            loc.neighbors(input.grid.cols(), input.grid.rows())
+                .filter(|loc| *input.grid.get(loc.y, loc.x).unwrap() != Plot::Rock),
+        );
+    }
+    new_locs
+}
+
+fn parse_input(input: &str) -> Result<Input> {
+    let mut grid = try_parse_grid(input, Plot::try_from)?;
+    let start =
+        position(&grid, |&plot| plot == Plot::Start).ok_or_else(|| anyhow!("no start plot 'S'"))?;
+    *grid.get_mut(start.y, start.x).unwrap() = Plot::Garden;
+
+    Ok(Input { grid, start })
+}
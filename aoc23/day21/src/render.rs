@@ -0,0 +1,29 @@
+//! Drawing the garden.
+
+use crate::{reachable_plots, Day21, Input, Location, Plot};
+use anyhow::Result;
+use aoc_common::params::Params;
+use aoc_common::render::{Canvas, Color, Style};
+use aoc_common::Solution;
+
+/// The garden with the plots reachable in part one's steps, as many as `params` says,
+/// highlighted.
+pub fn render(input: &str, params: &Params) -> Result<Canvas> {
+    let garden = Day21::parse(input)?;
+    Ok(draw(
+        &garden,
+        &reachable_plots(&garden, params.get("steps")),
+    ))
+}
+
+/// The garden with `plots` and the start highlighted.
+pub fn draw<'a>(garden: &Input, plots: impl IntoIterator<Item = &'a Location>) -> Canvas {
+    let mut canvas = Canvas::new(&garden.grid, |plot| match plot {
+        Plot::Rock => Style::new('#', Color::GREY),
+        Plot::Garden | Plot::Start => Style::new('.', Color::DARK_GREY),
+    });
+    canvas
+        .highlight(plots.into_iter().copied(), Color::GREEN)
+        .highlight([garden.start], Color::RED);
+    canvas
+}
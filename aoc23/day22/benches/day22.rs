@@ -0,0 +1,9 @@
+use aoc23_22::Day22;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn bench(c: &mut Criterion) {
+    aoc_common::bench::solution::<Day22>(c, 22);
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
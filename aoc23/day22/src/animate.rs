@@ -0,0 +1,78 @@
+//! Animating the falling bricks.
+
+use crate::{drop_brick, order_bricks, Brick, Day22};
+use anyhow::Result;
+use aoc_common::animate::Animation;
+use aoc_common::render::{Canvas, Color, Style};
+use aoc_common::{Grid, Point, Solution};
+use std::cmp::{max, min};
+
+const COLORS: [Color; 6] = [
+    Color::RED,
+    Color::GREEN,
+    Color::BLUE,
+    Color::YELLOW,
+    Color::rgb(211, 54, 130),
+    Color::rgb(42, 161, 152),
+];
+
+/// Each brick falling into place, in turn, seen from the side along the y axis.
+pub fn animate(input: &str, animation: &mut Animation) -> Result<()> {
+    let mut bricks = Day22::parse(input)?;
+    order_bricks(&mut bricks);
+    // Bricks only fall, so the first frame is the tallest.
+    let width = bricks
+        .iter()
+        .map(|b| max(b.0.x, b.1.x) + 1)
+        .max()
+        .unwrap_or(0);
+    let height = bricks
+        .iter()
+        .map(|b| max(b.0.z, b.1.z) + 1)
+        .max()
+        .unwrap_or(0);
+    let size = (width as usize, height as usize);
+
+    animation.step(|| draw(&bricks, size, None))?;
+    for i in 0..bricks.len() {
+        drop_brick(&mut bricks, i);
+        animation.step(|| draw(&bricks, size, Some(i)))?;
+    }
+    animation.finish(|| draw(&bricks, size, None))?;
+    Ok(())
+}
+
+/// The bricks from the front, each cell showing the nearest brick in it, above a row of
+/// ground. `landed` is highlighted.
+fn draw(bricks: &[Brick], (width, height): (usize, usize), landed: Option<usize>) -> Canvas {
+    // The nearest brick in each cell, with its distance.
+    let mut front: Grid<Option<(i64, usize)>> = Grid::new(height + 1, width);
+    for (i, brick) in bricks.iter().enumerate() {
+        let y = min(brick.0.y, brick.1.y);
+        for x in min(brick.0.x, brick.1.x)..=max(brick.0.x, brick.1.x) {
+            for z in min(brick.0.z, brick.1.z)..=max(brick.0.z, brick.1.z) {
+                let cell = &mut front[(height - 1 - z as usize, x as usize)];
+                if cell.is_none_or(|(nearest, _)| y < nearest) {
+                    *cell = Some((y, i));
+                }
+            }
+        }
+    }
+    for cell in front.iter_row_mut(height) {
+        *cell = Some((i64::MIN, usize::MAX));
+    }
+
+    let mut canvas = Canvas::new(&front, |cell| match cell {
+        None => Style::new(' ', Color::BLACK),
+        Some((_, usize::MAX)) => Style::new('-', Color::GREY),
+        Some((_, i)) => Style::new('#', COLORS[i % COLORS.len()]),
+    });
+    if let Some(landed) = landed {
+        let cells = front
+            .indexed_iter()
+            .filter(|(_, cell)| cell.is_some_and(|(_, i)| i == landed))
+            .map(|((row, col), _)| Point::new(col, row));
+        canvas.highlight(cells, Color::WHITE);
+    }
+    canvas
+}
@@ -0,0 +1,57 @@
+//! Random snapshots of falling bricks. `size` is the number of bricks.
+
+use rand::Rng;
+use std::collections::HashSet;
+
+const WIDTH: usize = 10;
+
+/// Bricks of one to four cubes along any axis, standing ones included, floating without
+/// touching each other over a 10 x 10 area, all above the ground at z = 0.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut occupied = HashSet::new();
+    let mut snapshot = String::new();
+    let mut placed = 0;
+    while placed < size {
+        let axis = rng.gen_range(0..3);
+        let length = rng.gen_range(1..=4);
+        let mut start = [
+            rng.gen_range(0..WIDTH),
+            rng.gen_range(0..WIDTH),
+            rng.gen_range(1..=2 * size),
+        ];
+        if axis < 2 {
+            start[axis] = start[axis].min(WIDTH - length);
+        }
+        let cubes: Vec<[usize; 3]> = (0..length)
+            .map(|i| {
+                let mut cube = start;
+                cube[axis] += i;
+                cube
+            })
+            .collect();
+        if cubes.iter().any(|cube| occupied.contains(cube)) {
+            continue;
+        }
+        occupied.extend(cubes.iter().copied());
+        let end = cubes[length - 1];
+        snapshot.push_str(&format!(
+            "{},{},{}~{},{},{}\n",
+            start[0], start[1], start[2], end[0], end[1], end[2]
+        ));
+        placed += 1;
+    }
+    snapshot
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day22;
+    use aoc_common::generate::exercise;
+
+    #[test]
+    fn generated_inputs_solve() {
+        exercise::<Day22>(generate, 20, 50);
+    }
+}
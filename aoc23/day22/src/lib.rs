@@ -0,0 +1,143 @@
+use std::{
+    cmp::{max, min},
+    collections::HashMap,
+};
+
+use anyhow::Result;
+use aoc_common::parse::{lines, parse_all, tag, IResult};
+use aoc_common::{Solution, Unsolved};
+use derive_more::Constructor;
+use nom::{
+    character::complete::i64 as parse_i64,
+    character::complete::line_ending,
+    combinator::{map, opt},
+    sequence::{separated_pair, terminated, tuple},
+};
+use range_ext::intersect::Intersect;
+
+pub mod animate;
+#[cfg(any(test, feature = "generate"))]
+pub mod generate;
+
+#[derive(Constructor, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Point3d {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+#[derive(Constructor, Clone, Copy, Debug)]
+/// A brick, from one corner cube to the opposite one.
+pub struct Brick(pub Point3d, pub Point3d);
+
+impl Brick {
+    /// Whether the bricks overlap when seen from above.
+    pub fn overlaps(&self, other: &Brick) -> bool {
+        let this_xrange = min(self.0.x, self.1.x)..=max(self.0.x, self.1.x);
+        let this_yrange = min(self.0.y, self.1.y)..=max(self.0.y, self.1.y);
+        let other_xrange = min(other.0.x, other.1.x)..=max(other.0.x, other.1.x);
+        let other_yrange = min(other.0.y, other.1.y)..=max(other.0.y, other.1.y);
+
+        this_xrange.intersect(&other_xrange).is_any()
+            && this_yrange.intersect(&other_yrange).is_any()
+    }
+}
+
+pub type Input = Vec<Brick>;
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Input;
+    type PartOne = usize;
+    type PartTwo = Unsolved;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_all(input, parse_input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(_input: &Self::Input) -> Self::PartTwo {
+        Unsolved
+    }
+}
+
+fn part_one(bricks: &Input) -> usize {
+    let mut bricks = bricks.clone();
+    order_bricks(&mut bricks);
+    let on_top_of = drop_bricks(&mut bricks);
+    assert!(on_top_of.len() == bricks.len());
+
+    let mut ok_to_disintegrate = vec![true; bricks.len()];
+    for (_, deps) in on_top_of {
+        if deps.len() == 1 {
+            ok_to_disintegrate[deps[0]] = false;
+        }
+    }
+
+    ok_to_disintegrate.iter().filter(|ok| **ok).count()
+}
+
+/// Sort the bricks by the height of their tops.
+pub fn order_bricks(bricks: &mut Input) {
+    bricks.sort_unstable_by_key(|brick| max(brick.0.z, brick.1.z));
+}
+
+/// Let the bricks, in [`order_bricks`] order, fall as far as they will. Returns the bricks that
+/// each one comes to rest on, by index.
+pub fn drop_bricks(bricks: &mut Input) -> HashMap<usize, Vec<usize>> {
+    (0..bricks.len())
+        .map(|i| (i, drop_brick(bricks, i)))
+        .collect()
+}
+
+/// Let brick `i` fall onto the bricks before it, which have already fallen. Returns the bricks
+/// it comes to rest on.
+pub fn drop_brick(bricks: &mut Input, i: usize) -> Vec<usize> {
+    let mut brick = bricks[i];
+    let below = bricks[0..i]
+        .iter()
+        .enumerate()
+        .filter(|(_, b)| max(b.0.z, b.1.z) < min(brick.0.z, brick.1.z))
+        .filter(|(_, b)| brick.overlaps(b));
+
+    let max_z = below
+        .clone()
+        .map(|(_, b)| max(b.0.z, b.1.z))
+        .max()
+        .unwrap_or(-1);
+
+    let brick_on_top_of = below
+        .filter(|(_, b)| max(b.0.z, b.1.z) == max_z)
+        .map(|(idx, _)| idx)
+        .collect::<Vec<_>>();
+
+    let drop = min(brick.0.z, brick.1.z) - max_z - 1;
+    assert!(drop >= 0);
+    brick.0.z -= drop;
+    brick.1.z -= drop;
+    bricks[i] = brick;
+
+    brick_on_top_of
+}
+
+fn parse_input(input: &str) -> IResult<&str, Input> {
+    terminated(lines(parse_brick), opt(line_ending))(input)
+}
+
+fn parse_brick(input: &str) -> IResult<&str, Brick> {
+    map(
+        separated_pair(parse_point, tag("~"), parse_point),
+        |(a, b)| Brick::new(a, b),
+    )(input)
+}
+
+fn parse_point(input: &str) -> IResult<&str, Point3d> {
+    map(
+        tuple((parse_i64, tag(","), parse_i64, tag(","), parse_i64)),
+        |(x, _, y, _, z)| Point3d::new(x, y, z),
+    )(input)
+}
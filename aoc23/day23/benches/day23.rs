@@ -0,0 +1,9 @@
+use aoc23_23::Day23;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn bench(c: &mut Criterion) {
+    aoc_common::bench::solution::<Day23>(c, 23);
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
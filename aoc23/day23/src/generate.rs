@@ -0,0 +1,67 @@
+//! Random hiking trail maps. `size` is roughly the width and height of the map.
+
+use aoc_common::generate::random_tree;
+use aoc_common::Point;
+use rand::seq::IteratorRandom;
+use rand::Rng;
+use std::cmp::Ordering;
+
+/// A maze of one-tile paths with an opening in the top and bottom rows. The paths form a tree
+/// grown from the top opening, with slopes that only ever lead away from it, plus a few extra
+/// openings to give some choice of route.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let cells = (size / 2).max(1);
+    let tiles = 2 * cells + 1;
+    let mut map = vec![vec!['#'; tiles]; tiles];
+    let tile = |cell: Point<usize>| Point::new(2 * cell.x + 1, 2 * cell.y + 1);
+    let between = |a: Point<usize>, b: Point<usize>| Point::new(a.x + b.x + 1, a.y + b.y + 1);
+
+    let root = Point::new(rng.gen_range(0..cells), 0);
+    map[0][tile(root).x] = '.';
+    map[tiles - 1][2 * rng.gen_range(0..cells) + 1] = '.';
+    for y in 0..cells {
+        for x in 0..cells {
+            let t = tile(Point::new(x, y));
+            map[t.y][t.x] = '.';
+        }
+    }
+    for (parent, child) in random_tree(rng, cells, cells, root, cells * cells) {
+        let t = between(parent, child);
+        map[t.y][t.x] = if rng.gen_bool(0.3) {
+            match (child.x.cmp(&parent.x), child.y.cmp(&parent.y)) {
+                (Ordering::Less, _) => '<',
+                (Ordering::Greater, _) => '>',
+                (_, Ordering::Less) => '^',
+                _ => 'v',
+            }
+        } else {
+            '.'
+        };
+    }
+    // Knock through a few more walls between neighbouring cells.
+    for _ in 0..rng.gen_range(0..=6) {
+        let cell = Point::new(rng.gen_range(0..cells), rng.gen_range(0..cells));
+        if let Some(next) = cell.neighbors(cells, cells).choose(rng) {
+            let t = between(cell, next);
+            if map[t.y][t.x] == '#' {
+                map[t.y][t.x] = '.';
+            }
+        }
+    }
+
+    map.iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day23;
+    use aoc_common::generate::exercise;
+
+    #[test]
+    fn generated_inputs_solve() {
+        exercise::<Day23>(generate, 20, 20);
+    }
+}
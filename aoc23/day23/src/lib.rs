@@ -0,0 +1,146 @@
+use anyhow::anyhow;
+use anyhow::Error;
+use anyhow::Result;
+use aoc_common::grid::try_parse_grid;
+use aoc_common::parse::BadChar;
+use aoc_common::{Direction, Grid, Point, Solution, Unsolved};
+use petgraph::algo::all_simple_paths;
+use petgraph::graphmap::GraphMap;
+use petgraph::Directed;
+
+#[cfg(any(test, feature = "generate"))]
+pub mod generate;
+pub mod render;
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Tile {
+    Wall,
+    Open,
+    Down,
+    Up,
+    Left,
+    Right,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = BadChar;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '#' => Ok(Self::Wall),
+            '.' => Ok(Self::Open),
+            'v' => Ok(Self::Down),
+            '^' => Ok(Self::Up),
+            '<' => Ok(Self::Left),
+            '>' => Ok(Self::Right),
+            found => Err(BadChar {
+                found,
+                expected: "#.v^<>",
+            }),
+        }
+    }
+}
+
+pub struct Scene(pub Grid<Tile>);
+
+pub type Location = Point<usize>;
+
+fn neighbors(
+    location: Location,
+    width: usize,
+    height: usize,
+    tile: Tile,
+) -> impl Iterator<Item = Location> {
+    let directions: &'static [Direction] = match tile {
+        Tile::Wall => &[],
+        Tile::Open => &Direction::ALL,
+        Tile::Down => &[Direction::Down],
+        Tile::Up => &[Direction::Up],
+        Tile::Left => &[Direction::Left],
+        Tile::Right => &[Direction::Right],
+    };
+    directions
+        .iter()
+        .filter_map(move |&dir| location.step(dir, width, height))
+}
+
+/// The open tiles, with an edge for each step that can be taken from one to the next.
+pub struct SceneGraph {
+    pub graph: GraphMap<Location, (), Directed>,
+    pub start: Location,
+    pub end: Location,
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = SceneGraph;
+    type PartOne = usize;
+    type PartTwo = Unsolved;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        make_graph(&parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(_input: &Self::Input) -> Self::PartTwo {
+        Unsolved
+    }
+}
+
+fn part_one(graph: &SceneGraph) -> usize {
+    longest_path(graph).len() - 1
+}
+
+/// The tiles along the longest hike from the start to the end that never steps on a tile twice.
+pub fn longest_path(graph: &SceneGraph) -> Vec<Location> {
+    let paths = all_simple_paths::<Vec<_>, _>(&graph.graph, graph.start, graph.end, 1, None);
+    paths.max_by_key(|path| path.len()).expect("no paths found")
+}
+
+/// Build the graph of steps through `input`, from the open tile on the top row to the one on the
+/// bottom row.
+pub fn make_graph(input: &Scene) -> Result<SceneGraph, Error> {
+    let mut graph = GraphMap::new();
+    let mut start = None;
+    let mut end = None;
+
+    for row in 0..input.0.rows() {
+        for col in 0..input.0.cols() {
+            let tile = *input.0.get(row, col).ok_or(anyhow!("missing point"))?;
+            let loc = Location::new(col, row);
+            if tile == Tile::Wall {
+                continue;
+            }
+            if row == 0 {
+                start = Some(loc);
+            }
+            if row == input.0.rows() - 1 {
+                end = Some(loc);
+            }
+            for neighbor in neighbors(loc, input.0.cols(), input.0.rows(), tile) {
+                let neighbor_tile = *input
+                    .0
+                    .get(neighbor.y, neighbor.x)
+                    .ok_or(anyhow!("missing point"))?;
+                if neighbor_tile != Tile::Wall {
+                    graph.add_edge(loc, neighbor, ());
+                }
+            }
+        }
+    }
+
+    Ok(SceneGraph {
+        graph,
+        start: start.ok_or(anyhow!("didn't find start"))?,
+        end: end.ok_or(anyhow!("didn't find end"))?,
+    })
+}
+
+/// Parse the map. [`Day23`]'s input is the graph made from it.
+pub fn parse_input(input: &str) -> Result<Scene, Error> {
+    Ok(Scene(try_parse_grid(input, Tile::try_from)?))
+}
@@ -0,0 +1,21 @@
+//! Drawing the trails.
+
+use crate::{longest_path, make_graph, parse_input, Tile};
+use anyhow::Result;
+use aoc_common::render::{Canvas, Color, Style};
+
+/// The trail map with part one's longest hike drawn on it.
+pub fn render(input: &str) -> Result<Canvas> {
+    let scene = parse_input(input)?;
+    let graph = make_graph(&scene)?;
+    let mut canvas = Canvas::new(&scene.0, |tile| match tile {
+        Tile::Wall => Style::new('#', Color::DARK_GREY),
+        Tile::Open => Style::new('.', Color::GREY),
+        Tile::Down => Style::new('v', Color::WHITE),
+        Tile::Up => Style::new('^', Color::WHITE),
+        Tile::Left => Style::new('<', Color::WHITE),
+        Tile::Right => Style::new('>', Color::WHITE),
+    });
+    canvas.path(longest_path(&graph), Color::YELLOW);
+    Ok(canvas)
+}
@@ -1,5 +1,8 @@
+import argparse
+import os
+import sys
 from itertools import combinations
-from typing import NamedTuple, List, Optional
+from typing import NamedTuple, List, Optional, TextIO
 
 
 MIN_XY = 200000000000000
@@ -47,15 +50,14 @@ def intersect(a: Vector, b: Vector, c: Vector, d: Vector) -> Optional[Vector]:
         return Vector(x, y, 0)
 
 
-def parse() -> List[Hailstone]:
+def parse(f: TextIO) -> List[Hailstone]:
     hailstones = []
-    with open("../inputs/day24.txt") as f:
-        for line in f:
-            line = line.strip()
-            [p, v] = line.split(' @ ')
-            [px, py, pz] = map(lambda x: float(x), p.split(', '))
-            [vx, vy, vz] = map(lambda x: float(x), v.split(', '))
-            hailstones.append(Hailstone(Vector(px, py, pz), Vector(vx, vy, vz)))
+    for line in f:
+        line = line.strip()
+        [p, v] = line.split(' @ ')
+        [px, py, pz] = map(lambda x: float(x), p.split(', '))
+        [vx, vy, vz] = map(lambda x: float(x), v.split(', '))
+        hailstones.append(Hailstone(Vector(px, py, pz), Vector(vx, vy, vz)))
     return hailstones
 
 
@@ -75,8 +77,22 @@ def part_one(hailstones: List[Hailstone]) -> int:
     return count
 
 
+def default_input() -> str:
+    directory = os.environ.get("AOC_INPUT_DIR", "../inputs")
+    return os.path.join(directory, "day24.txt")
+
+
 def run():
-    hailstones = parse()
+    parser = argparse.ArgumentParser()
+    parser.add_argument("-i", "--input", default=default_input(),
+                        help="puzzle input file, or - for stdin "
+                             "[default: $AOC_INPUT_DIR/day24.txt, or ../inputs/day24.txt]")
+    args = parser.parse_args()
+    if args.input == "-":
+        hailstones = parse(sys.stdin)
+    else:
+        with open(args.input) as f:
+            hailstones = parse(f)
     print(part_one(hailstones))
 
 
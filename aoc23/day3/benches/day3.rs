@@ -0,0 +1,9 @@
+use aoc23_3::Day3;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn bench(c: &mut Criterion) {
+    aoc_common::bench::solution::<Day3>(c, 3);
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -0,0 +1,45 @@
+//! Random engine schematics. `size` is the width and height of the schematic.
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const SYMBOLS: &[u8] = b"*#+$/@%=&";
+
+/// A square schematic of numbers up to 999 and symbols scattered over a background of `.`.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut schematic = String::new();
+    for _ in 0..size {
+        let mut line = String::new();
+        while line.len() < size {
+            let digits = rng.gen_range(1..=3).min(size - line.len());
+            match rng.gen_range(0..6) {
+                0 | 1 => {
+                    line.push_str(&rng.gen_range(1..10u32.pow(digits as u32)).to_string());
+                    // Keep the next number from running into this one.
+                    if line.len() < size {
+                        line.push('.');
+                    }
+                }
+                2 => line.push(*SYMBOLS.choose(rng).unwrap() as char),
+                _ => line.push('.'),
+            }
+        }
+        line.truncate(size);
+        schematic.push_str(&line);
+        schematic.push('\n');
+    }
+    schematic
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day3;
+    use aoc_common::generate::exercise;
+
+    #[test]
+    fn generated_inputs_solve() {
+        exercise::<Day3>(generate, 20, 50);
+    }
+}
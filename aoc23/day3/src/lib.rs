@@ -0,0 +1,147 @@
+use anyhow::Result;
+use aoc_common::parse::ParseError;
+use aoc_common::{Point, Solution};
+use regex::Regex;
+
+#[cfg(any(test, feature = "generate"))]
+pub mod generate;
+
+pub type Location = Point<usize>;
+
+/// The cells a number covers, from `start` to `end` inclusive, on one line.
+pub struct Span {
+    pub start: Location,
+    pub end: Location,
+}
+
+impl Span {
+    /// Whether `p` is in or next to this span, diagonally included.
+    pub fn within_point(&self, p: &Location) -> bool {
+        let ul = Point {
+            x: if self.start.x == 0 {
+                0
+            } else {
+                self.start.x - 1
+            },
+            y: if self.start.y == 0 {
+                0
+            } else {
+                self.start.y - 1
+            },
+        };
+        let br = Point {
+            x: self.end.x + 1,
+            y: self.end.y + 1,
+        };
+        ul.x <= p.x && p.x <= br.x && ul.y <= p.y && p.y <= br.y
+    }
+
+    pub fn within_symbols<'a, I>(&self, ps: I) -> bool
+    where
+        I: IntoIterator<Item = &'a Symbol>,
+    {
+        ps.into_iter().any(|s| self.within_point(&s.loc))
+    }
+}
+
+pub struct Number {
+    pub n: u64,
+    pub loc: Span,
+}
+
+pub struct Symbol {
+    pub is_gear: bool,
+    pub loc: Location,
+}
+
+pub struct Schematic {
+    pub numbers: Vec<Number>,
+    pub symbols: Vec<Symbol>,
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Schematic;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (numbers, symbols) = parse_input(input)?;
+        Ok(Schematic { numbers, symbols })
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        part_one(&input.numbers, &input.symbols)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        part_two(&input.numbers, &input.symbols)
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Vec<Number>, Vec<Symbol>), ParseError> {
+    let number_re = Regex::new("\\d+").unwrap();
+    let symbol_re = Regex::new("[^\\d.]").unwrap();
+
+    // One pass over the lines, finding the numbers and symbols on each.
+    let mut numbers = Vec::new();
+    let mut symbols = Vec::new();
+    for (line_nr, line) in input.lines().enumerate() {
+        for num_match in number_re.find_iter(line) {
+            let n = num_match
+                .as_str()
+                .parse::<u64>()
+                .map_err(|e| ParseError::new(line_nr + 1, num_match.start() + 1, e.to_string()))?;
+            numbers.push(Number {
+                n,
+                loc: Span {
+                    start: Point {
+                        x: num_match.start(),
+                        y: line_nr,
+                    },
+                    end: Point {
+                        x: num_match.end() - 1,
+                        y: line_nr,
+                    },
+                },
+            });
+        }
+        symbols.extend(symbol_re.find_iter(line).map(|sym_match| Symbol {
+            is_gear: sym_match.as_str().contains('*'),
+            loc: Point {
+                x: sym_match.start(),
+                y: line_nr,
+            },
+        }));
+    }
+
+    Ok((numbers, symbols))
+}
+
+fn part_one(numbers: &[Number], symbols: &[Symbol]) -> u64 {
+    numbers
+        .iter()
+        .filter(|n| n.loc.within_symbols(symbols))
+        .map(|n| n.n)
+        .sum()
+}
+
+fn part_two(numbers: &[Number], symbols: &[Symbol]) -> u64 {
+    symbols
+        .iter()
+        .filter(|s| s.is_gear)
+        .filter_map(|s| {
+            let (count, gear_ratio) = numbers
+                .iter()
+                .filter(|n| n.loc.within_point(&s.loc))
+                .map(|s| (1, s.n))
+                .fold((0, 1), |(ac, ap), (c, p)| (ac + c, ap * p));
+            if count == 2 {
+                Some(gear_ratio)
+            } else {
+                None
+            }
+        })
+        .sum()
+}
@@ -0,0 +1,9 @@
+use aoc23_4::Day4;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn bench(c: &mut Criterion) {
+    aoc_common::bench::solution::<Day4>(c, 4);
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
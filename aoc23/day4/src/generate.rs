@@ -0,0 +1,67 @@
+//! Random scratchcards. `size` is the number of cards.
+
+use rand::seq::{index, SliceRandom};
+use rand::Rng;
+
+/// Cards of five winning numbers and eight numbers we have, all below 100.
+///
+/// A card never wins more copies than there are cards after it, and cards that already have
+/// many copies win none, so that part two's total stays well within a `u32`.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut copies = vec![1u32; size];
+    let mut cards = String::new();
+    for i in 0..size {
+        let remaining = size - i - 1;
+        let this_copies = copies[i];
+        let matches = if this_copies > 1000 {
+            0
+        } else {
+            rng.gen_range(0..=remaining.min(5))
+        };
+        for v in &mut copies[i + 1..i + 1 + matches] {
+            *v += this_copies;
+        }
+
+        // The first `matches` numbers we have are winners; the rest of either list aren't.
+        let numbers: Vec<usize> = index::sample(rng, 99, 13 - matches)
+            .into_iter()
+            .map(|n| n + 1)
+            .collect();
+        let (winning, rest) = numbers.split_at(5);
+        let mut have: Vec<usize> = winning[..matches].iter().chain(rest).copied().collect();
+        have.shuffle(rng);
+
+        let list = |numbers: &[usize]| {
+            numbers
+                .iter()
+                .map(|n| format!("{:>2}", n))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        cards.push_str(&format!(
+            "Card {:>3}: {} | {}\n",
+            i + 1,
+            list(winning),
+            list(&have)
+        ));
+    }
+    cards
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day4;
+    use aoc_common::generate::{exercise, exercise_streaming};
+
+    #[test]
+    fn generated_inputs_solve() {
+        exercise::<Day4>(generate, 20, 50);
+    }
+
+    #[test]
+    fn generated_inputs_stream() {
+        exercise_streaming::<Day4>(generate, 20, 50);
+    }
+}
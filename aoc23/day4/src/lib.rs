@@ -0,0 +1,186 @@
+use anyhow::Result;
+use aoc_common::parse::{lines, parse_all, tag, IResult};
+use aoc_common::stream::Streaming;
+use aoc_common::Solution;
+use nom::character::complete::{line_ending, space0, space1, u32 as nom32};
+use nom::combinator::{all_consuming, map, opt};
+use nom::multi::separated_list0;
+use nom::sequence::{delimited, terminated, tuple};
+use std::collections::VecDeque;
+
+#[cfg(any(test, feature = "generate"))]
+pub mod generate;
+
+pub struct Card {
+    pub winning: Vec<u32>,
+    pub have: Vec<u32>,
+}
+
+pub struct Input {
+    pub cards: Vec<Card>,
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Input;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_all(input, parse_input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        part_two(input)
+    }
+}
+
+/// What's known after some of the cards: part one's score, the cards won so far, and the
+/// copies won of each of the cards still to come.
+#[derive(Default)]
+pub struct State {
+    score: u32,
+    total_cards: u32,
+    copies: VecDeque<u32>,
+}
+
+impl Streaming for Day4 {
+    type State = State;
+
+    fn feed(state: &mut State, line: &str) -> Result<()> {
+        for card in parse_all(line, parse_input)?.cards {
+            state.score += card_score(&card);
+            let this_copies = 1 + state.copies.pop_front().unwrap_or(0);
+            let matches = matches(&card);
+            if state.copies.len() < matches {
+                state.copies.resize(matches, 0);
+            }
+            for v in state.copies.range_mut(..matches) {
+                *v += this_copies;
+            }
+            state.total_cards += this_copies;
+        }
+        Ok(())
+    }
+
+    fn finish_one(state: &State) -> u32 {
+        state.score
+    }
+
+    fn finish_two(state: &State) -> u32 {
+        state.total_cards
+    }
+}
+
+fn part_one(input: &Input) -> u32 {
+    input.cards.iter().map(card_score).sum()
+}
+
+fn part_two(input: &Input) -> u32 {
+    let mut total_cards: u32 = 0;
+    let mut copies = vec![1; input.cards.len()];
+
+    for (i, card) in input.cards.iter().enumerate() {
+        let matches = matches(card);
+        let this_copies = copies[i];
+        for v in &mut copies[i + 1..i + 1 + matches] {
+            *v += this_copies;
+        }
+        total_cards += this_copies;
+    }
+
+    total_cards
+}
+
+/// Part one's score for `card`: 1 for the first winning number, doubled for each after.
+pub fn card_score(card: &Card) -> u32 {
+    let count = matches(card);
+    if count > 0 {
+        1 << (count - 1)
+    } else {
+        0
+    }
+}
+
+/// How many of the numbers on `card` are winning numbers.
+pub fn matches(card: &Card) -> usize {
+    card.have
+        .iter()
+        .filter(|n| card.winning.contains(n))
+        .count()
+}
+
+fn parse_input(input: &str) -> IResult<&str, Input> {
+    all_consuming(map(terminated(lines(card), opt(line_ending)), |c| Input {
+        cards: c,
+    }))(input)
+}
+
+fn card(input: &str) -> IResult<&str, Card> {
+    map(
+        tuple((
+            header,
+            numberlist,
+            delimited(space0, tag("|"), space0),
+            numberlist,
+        )),
+        |(_, winning, _, have)| Card { winning, have },
+    )(input)
+}
+
+fn header(input: &str) -> IResult<&str, u32> {
+    delimited(
+        terminated(tag("Card"), space1),
+        nom32,
+        terminated(tag(":"), space1),
+    )(input)
+}
+
+fn numberlist(input: &str) -> IResult<&str, Vec<u32>> {
+    separated_list0(space1, nom32)(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn parse_numlist() {
+        assert_eq!(numberlist("1  2 23    6"), Ok(("", vec![1, 2, 23, 6])))
+    }
+
+    #[test]
+    fn parse_card() {
+        let r = card("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53");
+        assert!(r.is_ok());
+        assert!(r.unwrap().0.is_empty());
+    }
+
+    #[test]
+    fn parse_example() {
+        let s = fs::read_to_string("../examples/day4.txt").unwrap();
+        let r = parse_input(&s);
+        assert!(r.is_ok());
+        assert!(r.unwrap().0.is_empty());
+    }
+
+    #[test]
+    fn example_part_one() {
+        let s = fs::read_to_string("../examples/day4.txt").unwrap();
+        let (_, i) = parse_input(&s).unwrap();
+        assert_eq!(part_one(&i), 13);
+    }
+
+    #[test]
+    fn example_part_two() {
+        let s = fs::read_to_string("../examples/day4.txt").unwrap();
+        let (_, i) = parse_input(&s).unwrap();
+        assert_eq!(part_two(&i), 30);
+    }
+}
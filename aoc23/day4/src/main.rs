@@ -1,130 +1,47 @@
-use nom::bytes::complete::tag;
-use nom::character::complete::{line_ending, space0, space1, u32 as nom32};
-use nom::combinator::{all_consuming, map, opt};
-use nom::multi::{separated_list0, separated_list1};
-use nom::sequence::{delimited, terminated, tuple};
-use nom::IResult;
-use std::fs;
-
-struct Card {
-    winning: Vec<u32>,
-    have: Vec<u32>,
-}
-
-struct Input {
-    cards: Vec<Card>,
-}
-
-fn main() {
-    let input_s = fs::read_to_string("inputs/day4.txt").unwrap();
-    let (_, input) = parse_input(&input_s).unwrap();
-    println!("Part one: {}", part_one(&input));
-    println!("Part two: {}", part_two(&input));
-}
-
-fn part_one(input: &Input) -> u32 {
-    input.cards.iter().map(card_score).sum()
-}
-
-fn part_two(input: &Input) -> u32 {
-    let mut total_cards: u32 = 0;
-    let mut copies = vec![1; input.cards.len()];
-
-    for (i, card) in input.cards.iter().enumerate() {
-        let matches = card
-            .have
-            .iter()
-            .filter(|n| card.winning.contains(n))
-            .count();
-        let this_copies = copies[i];
-        for v in &mut copies[i + 1..i + 1 + matches] {
-            *v += this_copies;
+use anyhow::Result;
+use aoc23_4::Day4;
+use aoc_common::input::InputArgs;
+use aoc_common::params::{self, ParamArgs};
+use aoc_common::stream;
+use aoc_common::trace::TraceArgs;
+use clap::Parser;
+
+#[derive(Debug, Parser)]
+#[command(about)]
+struct Args {
+    #[command(flatten)]
+    input: InputArgs,
+
+    #[command(flatten)]
+    params: ParamArgs,
+
+    #[command(flatten)]
+    trace: TraceArgs,
+
+    /// Solve the input as it's read rather than reading it all first, for very large inputs
+    #[arg(long, conflicts_with = "params")]
+    stream: bool,
+}
+
+fn main() -> Result<()> {
+    let args = params::parse_args::<Args, Day4>();
+    let _trace = args.trace.start("day4")?;
+
+    if args.stream {
+        let report = stream::solve::<Day4>(&mut args.input.open(4)?, &args.params.parts())?;
+        for part in &report.parts {
+            if let Some(answer) = &part.answer {
+                println!("Part {}: {}", part.part, answer);
+            }
         }
-        total_cards += this_copies;
-    }
-
-    total_cards
-}
-
-fn card_score(card: &Card) -> u32 {
-    let count = card
-        .have
-        .iter()
-        .filter(|n| card.winning.contains(n))
-        .count();
-    if count > 0 {
-        1 << (count - 1)
-    } else {
-        0
+        return Ok(());
     }
-}
-
-fn parse_input(input: &str) -> IResult<&str, Input> {
-    all_consuming(map(
-        terminated(separated_list1(line_ending, card), opt(line_ending)),
-        |c| Input { cards: c },
-    ))(input)
-}
 
-fn card(input: &str) -> IResult<&str, Card> {
-    map(
-        tuple((
-            header,
-            numberlist,
-            delimited(space0, tag("|"), space0),
-            numberlist,
-        )),
-        |(_, winning, _, have)| Card { winning, have },
-    )(input)
-}
-
-fn header(input: &str) -> IResult<&str, u32> {
-    delimited(
-        terminated(tag("Card"), space1),
-        nom32,
-        terminated(tag(":"), space1),
-    )(input)
-}
-
-fn numberlist(input: &str) -> IResult<&str, Vec<u32>> {
-    separated_list0(space1, nom32)(input)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn parse_numlist() {
-        assert_eq!(numberlist("1  2 23    6"), Ok(("", vec![1, 2, 23, 6])))
-    }
-
-    #[test]
-    fn parse_card() {
-        let r = card("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53");
-        assert!(r.is_ok());
-        assert!(r.unwrap().0.is_empty());
-    }
-
-    #[test]
-    fn parse_example() {
-        let s = fs::read_to_string("example.txt").unwrap();
-        let r = parse_input(&s);
-        assert!(r.is_ok());
-        assert!(r.unwrap().0.is_empty());
-    }
-
-    #[test]
-    fn example_part_one() {
-        let s = fs::read_to_string("example.txt").unwrap();
-        let (_, i) = parse_input(&s).unwrap();
-        assert_eq!(part_one(&i), 13);
-    }
-
-    #[test]
-    fn example_part_two() {
-        let s = fs::read_to_string("example.txt").unwrap();
-        let (_, i) = parse_input(&s).unwrap();
-        assert_eq!(part_two(&i), 30);
+    let input = args.input.map(4)?;
+    for part in args.params.parts() {
+        if let Some(answer) = aoc_common::run::<Day4>(&input, part, &args.params.params)? {
+            println!("Part {}: {}", part, answer);
+        }
     }
+    Ok(())
 }
@@ -0,0 +1,9 @@
+use aoc23_5::Day5;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn bench(c: &mut Criterion) {
+    aoc_common::bench::solution::<Day5>(c, 5);
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
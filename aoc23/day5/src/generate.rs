@@ -0,0 +1,59 @@
+//! Random almanacs. `size` is the number of seed ranges and of ranges in each map.
+
+use rand::Rng;
+
+const CATEGORIES: [&str; 8] = [
+    "seed",
+    "soil",
+    "fertilizer",
+    "water",
+    "light",
+    "temperature",
+    "humidity",
+    "location",
+];
+
+/// The scale of the values in the almanac, a little under a real input's.
+const LIMIT: usize = 1_000_000_000;
+
+/// An almanac with the usual seven maps. Their source ranges never overlap, but are often
+/// adjacent.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let size = size.max(1);
+    let seeds: Vec<String> = (0..size)
+        .map(|_| {
+            let start = rng.gen_range(0..LIMIT);
+            format!("{} {}", start, rng.gen_range(1..=LIMIT / 10))
+        })
+        .collect();
+    let mut almanac = format!("seeds: {}\n", seeds.join(" "));
+
+    for names in CATEGORIES.windows(2) {
+        almanac.push_str(&format!("\n{}-to-{} map:\n", names[0], names[1]));
+        let mut end = 0;
+        for _ in 0..size {
+            let start = if rng.gen_bool(0.3) {
+                end
+            } else {
+                end + rng.gen_range(1..LIMIT / size)
+            };
+            let length = rng.gen_range(1..LIMIT / size);
+            end = start + length;
+            let dest = rng.gen_range(0..LIMIT);
+            almanac.push_str(&format!("{} {} {}\n", dest, start, length));
+        }
+    }
+    almanac
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day5;
+    use aoc_common::generate::exercise;
+
+    #[test]
+    fn generated_inputs_solve() {
+        exercise::<Day5>(generate, 20, 50);
+    }
+}
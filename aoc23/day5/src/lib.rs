@@ -0,0 +1,148 @@
+use anyhow::Result;
+use aoc_common::parse::{lines, parse_all, tag, IResult};
+use aoc_common::Solution;
+use nom::bytes::complete::is_not;
+use nom::character::complete::{i64 as nom64, multispace0, multispace1, space0, space1};
+use nom::combinator::{cut, map};
+use nom::multi::{many1, separated_list1};
+use nom::sequence::{delimited, tuple};
+use rangemap::{RangeMap, RangeSet};
+use std::cmp::{max, min};
+use std::ops::Range;
+
+#[cfg(any(test, feature = "generate"))]
+pub mod generate;
+
+/// One of the almanac's maps, as the offset to add to each range of source values.
+#[derive(Debug)]
+pub struct ElfMap {
+    pub data: RangeMap<i64, i64>,
+}
+
+impl From<Vec<(i64, i64, i64)>> for ElfMap {
+    fn from(input: Vec<(i64, i64, i64)>) -> Self {
+        let mut result = RangeMap::new();
+        for (dest_start, src_start, range) in input {
+            result.insert(src_start..src_start + range, dest_start - src_start);
+        }
+        ElfMap { data: result }
+    }
+}
+
+impl ElfMap {
+    pub fn map_value(&self, input: i64) -> i64 {
+        input + self.data.get(&input).unwrap_or(&0)
+    }
+
+    pub fn map_values(&self, values: &[i64]) -> Vec<i64> {
+        values.iter().map(|v| self.map_value(*v)).collect()
+    }
+
+    /// Map every value in `ranges` at once, splitting them where they straddle the map's ranges.
+    pub fn map_ranges(&self, ranges: &RangeSet<i64>) -> RangeSet<i64> {
+        let mut sharded_ranges: RangeSet<i64> = RangeSet::new();
+        for src_range in ranges.iter() {
+            for (dst_range, offset) in self.data.overlapping(src_range) {
+                let intersecting_range = range_intersect(src_range, dst_range);
+                let final_range =
+                    intersecting_range.start + offset..intersecting_range.end + offset;
+                sharded_ranges.insert(final_range);
+            }
+            for dst_range in self.data.gaps(src_range) {
+                sharded_ranges.insert(range_intersect(src_range, &dst_range));
+            }
+        }
+        sharded_ranges
+    }
+}
+
+#[derive(Debug)]
+pub struct Input {
+    pub seeds: Vec<i64>,
+    pub seed_ranges: RangeSet<i64>,
+    pub maps: Vec<ElfMap>,
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Input;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_all(input, parse_input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        part_two(input)
+    }
+}
+
+fn part_one(input: &Input) -> i64 {
+    *input
+        .maps
+        .iter()
+        .fold(input.seeds.clone(), |s, m| m.map_values(&s))
+        .iter()
+        .min()
+        .unwrap()
+}
+
+fn part_two(input: &Input) -> i64 {
+    input
+        .maps
+        .iter()
+        .fold(input.seed_ranges.clone(), |r, m| m.map_ranges(&r))
+        .iter()
+        .map(|r| r.start)
+        .min()
+        .unwrap()
+}
+
+fn range_intersect(a: &Range<i64>, b: &Range<i64>) -> Range<i64> {
+    max(a.start, b.start)..min(a.end, b.end)
+}
+
+fn parse_input(input: &str) -> IResult<&str, Input> {
+    map(tuple((seedlist, many1(elfmap))), |(seeds, maps)| Input {
+        seed_ranges: seed_input_to_ranges(&seeds),
+        seeds,
+        maps,
+    })(input)
+}
+
+fn seed_input_to_ranges(seeds: &[i64]) -> RangeSet<i64> {
+    let mut ret = RangeSet::new();
+    for slice in seeds.chunks_exact(2) {
+        ret.insert(slice[0]..slice[0] + slice[1]);
+    }
+    ret
+}
+
+fn seedlist(input: &str) -> IResult<&str, Vec<i64>> {
+    delimited(tag("seeds: "), separated_list1(space1, nom64), multispace1)(input)
+}
+
+fn elfmap(input: &str) -> IResult<&str, ElfMap> {
+    map(
+        delimited(
+            tuple((is_not(":"), tag(":"), multispace0)),
+            cut(elfmap_data),
+            multispace0,
+        ),
+        |x| x.into(),
+    )(input)
+}
+
+fn elfmap_data(input: &str) -> IResult<&str, Vec<(i64, i64, i64)>> {
+    lines(tuple((
+        delimited(space0, nom64, space0),
+        delimited(space0, nom64, space0),
+        delimited(space0, nom64, space0),
+    )))(input)
+}
@@ -0,0 +1,9 @@
+use aoc23_6::Day6;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn bench(c: &mut Criterion) {
+    aoc_common::bench::solution::<Day6>(c, 6);
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -0,0 +1,39 @@
+//! Random race sheets. `size` is the number of races, from one to four.
+
+use rand::Rng;
+
+/// Races that each have at least one way to win. Part two reads the whole sheet as one number
+/// per line, so more than four races would overflow it.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let races: Vec<(u64, u64)> = (0..size.clamp(1, 4))
+        .map(|_| {
+            let time = rng.gen_range(30..100);
+            let best = (time / 2) * (time - time / 2);
+            (time, rng.gen_range(100..best))
+        })
+        .collect();
+    let row = |values: Vec<u64>| {
+        values
+            .iter()
+            .map(|v| format!("{:>4}", v))
+            .collect::<Vec<_>>()
+            .join("   ")
+    };
+    format!(
+        "Time:     {}\nDistance: {}\n",
+        row(races.iter().map(|r| r.0).collect()),
+        row(races.iter().map(|r| r.1).collect())
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day6;
+    use aoc_common::generate::exercise;
+
+    #[test]
+    fn generated_inputs_solve() {
+        exercise::<Day6>(generate, 4, 50);
+    }
+}
@@ -0,0 +1,106 @@
+use anyhow::Result;
+use aoc_common::parse::{parse_all, tag, IResult};
+use aoc_common::Solution;
+use nom::bytes::complete::is_not;
+use nom::character::complete::{i64 as nom64, line_ending, space0, space1};
+use nom::combinator::map_res;
+use nom::multi::separated_list0;
+use nom::sequence::{delimited, pair};
+use std::cmp::{max, min};
+use std::iter::zip;
+
+#[cfg(any(test, feature = "generate"))]
+pub mod generate;
+pub mod reference;
+
+pub struct Race {
+    pub duration: i64,
+    pub distance_to_beat: i64,
+}
+
+/// The races read as separate numbers, and as one race with the spaces removed.
+pub struct Races {
+    pub races: Vec<Race>,
+    pub combined: Race,
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Races;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let races = parse_all(input, parse_input)?;
+        let combined = parse_all(input, parse_input_2)?;
+        Ok(Races { races, combined })
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        part_one(&input.races)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        part_two(&input.combined)
+    }
+}
+
+fn part_one(input: &[Race]) -> i64 {
+    input.iter().map(ways_to_beat).product()
+}
+
+fn part_two(race: &Race) -> i64 {
+    ways_to_beat(race)
+}
+
+/// How many whole numbers of milliseconds holding the button would beat the record.
+pub fn ways_to_beat(race: &Race) -> i64 {
+    let t: f64 = race.duration as f64;
+    let d: f64 = race.distance_to_beat as f64;
+    // find integers integers 0 <= b <= r where b * (r-b) > d
+    // which is 1/2 (r - sqrt(r^2 - 4 d)) < b < 1/2 (sqrt(r^2 - 4 d) + r)
+    let low = 0.5 * (t - (t.powi(2) - 4f64 * d).sqrt());
+    let high = 0.5 * (t + (t.powi(2) - 4f64 * d).sqrt());
+    let low = max(0, low.floor() as i64 + 1);
+    let high = min(race.duration, high.ceil() as i64 - 1);
+    max(0, high - low + 1)
+}
+
+fn parse_input(text: &str) -> IResult<&str, Vec<Race>> {
+    let (text, times) = delimited(
+        pair(tag("Time:"), space0),
+        separated_list0(space1, nom64),
+        line_ending,
+    )(text)?;
+    let (text, distances) = delimited(
+        pair(tag("Distance:"), space0),
+        separated_list0(space1, nom64),
+        line_ending,
+    )(text)?;
+    let races = zip(times, distances)
+        .map(|(t, d)| Race {
+            duration: t,
+            distance_to_beat: d,
+        })
+        .collect();
+    Ok((text, races))
+}
+
+fn parse_input_2(text: &str) -> IResult<&str, Race> {
+    let (text, time) = delimited(pair(tag("Time:"), space0), spaced_number, line_ending)(text)?;
+    let (text, dist) = delimited(pair(tag("Distance:"), space0), spaced_number, line_ending)(text)?;
+
+    Ok((
+        text,
+        Race {
+            duration: time,
+            distance_to_beat: dist,
+        },
+    ))
+}
+
+// A number written with spaces between its digits.
+fn spaced_number(text: &str) -> IResult<&str, i64> {
+    map_res(is_not("\n"), |s: &str| s.replace(' ', "").parse::<i64>())(text)
+}
@@ -0,0 +1,26 @@
+//! A naive reference solver, to check the closed form against.
+
+use crate::Race;
+
+/// The number of ways to beat the record, by trying every whole number of milliseconds.
+pub fn ways_to_beat(race: &Race) -> i64 {
+    (0..=race.duration)
+        .filter(|hold| hold * (race.duration - hold) > race.distance_to_beat)
+        .count() as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn agrees_with_ways_to_beat(duration in 0..2000i64, fraction in 0.0..1.1f64) {
+            // Records from nothing up to a little past the best possible distance.
+            let distance_to_beat = (fraction * (duration * duration / 4) as f64) as i64;
+            let race = Race { duration, distance_to_beat };
+            prop_assert_eq!(crate::ways_to_beat(&race), ways_to_beat(&race));
+        }
+    }
+}
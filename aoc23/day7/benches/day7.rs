@@ -0,0 +1,9 @@
+use aoc23_7::Day7;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn bench(c: &mut Criterion) {
+    aoc_common::bench::solution::<Day7>(c, 7);
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -0,0 +1,38 @@
+//! Random Camel Cards hands. `size` is the number of hands.
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const CARDS: &[u8] = b"23456789TJQKA";
+
+/// Hands of five random cards, with bids up to 1000.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let mut hands = String::new();
+    for _ in 0..size.max(1) {
+        // Draw from a few card values at a time, so pairs and better come up often.
+        let kinds = rng.gen_range(1..=5);
+        let values: Vec<u8> = CARDS.choose_multiple(rng, kinds).copied().collect();
+        let hand: String = (0..5)
+            .map(|_| *values.choose(rng).unwrap() as char)
+            .collect();
+        hands.push_str(&format!("{} {}\n", hand, rng.gen_range(1..=1000)));
+    }
+    hands
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day7;
+    use aoc_common::generate::{exercise, exercise_streaming};
+
+    #[test]
+    fn generated_inputs_solve() {
+        exercise::<Day7>(generate, 20, 50);
+    }
+
+    #[test]
+    fn generated_inputs_stream() {
+        exercise_streaming::<Day7>(generate, 20, 50);
+    }
+}
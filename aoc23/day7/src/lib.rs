@@ -0,0 +1,261 @@
+use anyhow::{anyhow, Result};
+use aoc_common::parse::ParseError;
+use aoc_common::stream::Streaming;
+use aoc_common::Solution;
+use counter::Counter;
+use itertools::Itertools;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+#[cfg(any(test, feature = "generate"))]
+pub mod generate;
+
+fn card_value(value: char) -> Result<u8> {
+    Ok(match value {
+        'A' => 14,
+        'K' => 13,
+        'Q' => 12,
+        'J' => 11,
+        'T' => 10,
+        _ => value
+            .to_digit(10)
+            .ok_or_else(|| anyhow!("Invalid card identifier: {}", value))? as u8,
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct Hand {
+    /// Card values, 2 to 14 for ace. A joker is 0.
+    pub hand: [u8; 5],
+    pub bid: i64,
+}
+
+impl Hand {
+    pub fn parse(hand: &str, bid: &str) -> Result<Hand> {
+        let bid = bid.parse::<i64>()?;
+        let hand: Vec<u8> = hand.chars().map(card_value).collect::<Result<Vec<u8>>>()?;
+        let hand: &[u8] = hand
+            .chunks_exact(5)
+            .next()
+            .ok_or_else(|| anyhow!("Short hand: {}", hand.len()))?;
+        Ok(Hand {
+            hand: hand.try_into()?,
+            bid,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct EvaluatedHand<'a> {
+    hand: &'a Hand,
+    counts: [u8; 5],
+}
+
+impl EvaluatedHand<'_> {
+    fn evaluate(hand: &Hand) -> EvaluatedHand<'_> {
+        // map of card value to number of that card
+        let card_counts: Counter<u8> = hand.hand.iter().cloned().collect();
+
+        // counts[i] is the number of different card values that we have i of
+        // for example, two pair has counts[2] == 2 and counts[1] == 1
+        let mut counts = [0; 5];
+        for (_, &count) in card_counts.iter().filter(|(v, _)| **v != 0u8) {
+            counts[5 - count] += 1;
+        }
+
+        // Make all jokers contribute to whatever card we have the most of.
+        if let Some(&jokers) = card_counts.get(&0u8) {
+            if let Some(highest_ct) = card_counts
+                .iter()
+                .filter(|(v, _)| **v != 0u8)
+                .map(|(_, c)| c)
+                .max()
+            {
+                counts[5 - *highest_ct] -= 1;
+                counts[5 - (*highest_ct + jokers)] += 1;
+            } else {
+                counts[0] = 1;
+            }
+        }
+
+        EvaluatedHand { hand, counts }
+    }
+}
+
+impl PartialEq<Self> for EvaluatedHand<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.hand.hand.eq(&other.hand.hand)
+    }
+}
+
+impl PartialOrd<Self> for EvaluatedHand<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Eq for EvaluatedHand<'_> {}
+
+impl Ord for EvaluatedHand<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.counts
+            .cmp(&other.counts)
+            .then(self.hand.hand.cmp(&other.hand.hand))
+    }
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<Hand>;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        part_two(input)
+    }
+}
+
+/// The bids on one hand of cards: how many, their sum, and the sum of each times the number
+/// of bids on the hand before it, as equal hands are ranked in the order they come.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Bids {
+    count: i64,
+    sum: i64,
+    weighted: i64,
+}
+
+/// The hands so far, grouped by their cards. There are only 13^5 hands of cards, so this is
+/// bounded however long the input is.
+#[derive(Debug, Default)]
+pub struct Hands(HashMap<[u8; 5], Bids>);
+
+impl Streaming for Day7 {
+    type State = Hands;
+
+    fn feed(state: &mut Hands, line: &str) -> Result<()> {
+        for hand in parse_input(line)? {
+            let bids = state.0.entry(hand.hand).or_default();
+            bids.weighted += bids.count * hand.bid;
+            bids.count += 1;
+            bids.sum += hand.bid;
+        }
+        Ok(())
+    }
+
+    fn finish_one(state: &Hands) -> i64 {
+        score_grouped(state, false)
+    }
+
+    fn finish_two(state: &Hands) -> i64 {
+        score_grouped(state, true)
+    }
+}
+
+fn part_one(input: &[Hand]) -> i64 {
+    score(input)
+}
+
+fn part_two(input: &[Hand]) -> i64 {
+    let mut input = input.to_vec();
+    for hand in &mut input {
+        hand.hand = with_jokers(hand.hand);
+    }
+    score(&input)
+}
+
+/// `cards` with the jacks as jokers.
+fn with_jokers(mut cards: [u8; 5]) -> [u8; 5] {
+    for card in &mut cards {
+        if *card == 11 {
+            *card = 0;
+        }
+    }
+    cards
+}
+
+/// The total winnings: each hand's bid times its rank, weakest first.
+pub fn score(input: &[Hand]) -> i64 {
+    let mut hands: Vec<EvaluatedHand> = input.iter().map(|h| EvaluatedHand::evaluate(h)).collect();
+    hands.sort();
+    hands
+        .iter()
+        .enumerate()
+        .map(|(rank, hand)| (rank + 1) as i64 * hand.hand.bid)
+        .sum()
+}
+
+/// As [`score`], for hands grouped by their cards, with the jacks as jokers if `jokers`.
+fn score_grouped(hands: &Hands, jokers: bool) -> i64 {
+    let played: Vec<(Hand, Bids)> = hands
+        .0
+        .iter()
+        .map(|(&cards, &bids)| {
+            let cards = if jokers { with_jokers(cards) } else { cards };
+            (
+                Hand {
+                    hand: cards,
+                    bid: 0,
+                },
+                bids,
+            )
+        })
+        .collect();
+    let mut evaluated: Vec<(EvaluatedHand, Bids)> = played
+        .iter()
+        .map(|(hand, bids)| (EvaluatedHand::evaluate(hand), *bids))
+        .collect();
+    evaluated.sort_by(|(a, _), (b, _)| a.cmp(b));
+    let mut below = 0;
+    evaluated
+        .iter()
+        .map(|(_, bids)| {
+            // Equal hands rank above all the weaker ones, in the order they came.
+            let winnings = (below + 1) * bids.sum + bids.weighted;
+            below += bids.count;
+            winnings
+        })
+        .sum()
+}
+
+fn parse_input(input: &str) -> Result<Vec<Hand>> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(row, line)| {
+            let (hand, bid) = line.split_whitespace().next_tuple().ok_or_else(|| {
+                ParseError::new(row + 1, line.len() + 1, "expected a hand and a bid")
+            })?;
+            Hand::parse(hand, bid).map_err(|e| ParseError::new(row + 1, 1, e.to_string()).into())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::stream::stream;
+
+    #[test]
+    fn equal_hands_stream_in_order() {
+        let input = "AAAAA 1\nJJJJJ 2\n22222 3\nAAAAA 5\nJJJJJ 7\nAAAAA 11\n";
+        let hands = Day7::parse(input).unwrap();
+        let state = stream::<Day7>(&mut input.as_bytes()).unwrap();
+        assert_eq!(state.0.len(), 3);
+        assert_eq!(Day7::finish_one(&state), Day7::part_one(&hands));
+        assert_eq!(Day7::finish_two(&state), Day7::part_two(&hands));
+        assert_eq!(
+            Day7::part_one(&hands),
+            3 + 2 * 2 + 3 * 7 + 4 + 5 * 5 + 6 * 11
+        );
+    }
+}
@@ -1,144 +1,47 @@
-use anyhow::{anyhow, Result};
-use counter::Counter;
-use itertools::Itertools;
-use std::cmp::Ordering;
-use std::fs;
-
-fn card_value(value: char) -> Result<u8> {
-    Ok(match value {
-        'A' => 14,
-        'K' => 13,
-        'Q' => 12,
-        'J' => 11,
-        'T' => 10,
-        _ => value
-            .to_digit(10)
-            .ok_or_else(|| anyhow!("Invalid card identifier: {}", value))? as u8,
-    })
-}
-
-#[derive(Debug, Clone)]
-struct Hand {
-    hand: [u8; 5],
-    bid: i64,
-}
-
-impl Hand {
-    fn parse(hand: &str, bid: &str) -> Result<Hand> {
-        let bid = bid.parse::<i64>()?;
-        let hand: Vec<u8> = hand.chars().map(card_value).collect::<Result<Vec<u8>>>()?;
-        let hand: &[u8] = hand
-            .chunks_exact(5)
-            .next()
-            .ok_or_else(|| anyhow!("Short hand: {}", hand.len()))?;
-        Ok(Hand {
-            hand: hand.try_into()?,
-            bid,
-        })
-    }
-}
-
-#[derive(Debug)]
-struct EvaluatedHand<'a> {
-    hand: &'a Hand,
-    counts: [u8; 5],
-}
-
-impl EvaluatedHand<'_> {
-    fn evaluate(hand: &Hand) -> EvaluatedHand {
-        // map of card value to number of that card
-        let card_counts: Counter<u8> = hand.hand.iter().cloned().collect();
-
-        // counts[i] is the number of different card values that we have i of
-        // for example, two pair has counts[2] == 2 and counts[1] == 1
-        let mut counts = [0; 5];
-        for (_, &count) in card_counts.iter().filter(|(v, _)| **v != 0u8) {
-            counts[5 - count] += 1;
-        }
-
-        // Make all jokers contribute to whatever card we have the most of.
-        if let Some(&jokers) = card_counts.get(&0u8) {
-            if let Some(highest_ct) = card_counts
-                .iter()
-                .filter(|(v, _)| **v != 0u8)
-                .map(|(_, c)| c)
-                .max()
-            {
-                counts[5 - *highest_ct] -= 1;
-                counts[5 - (*highest_ct + jokers)] += 1;
-            } else {
-                counts[0] = 1;
+use anyhow::Result;
+use aoc23_7::Day7;
+use aoc_common::input::InputArgs;
+use aoc_common::params::{self, ParamArgs};
+use aoc_common::stream;
+use aoc_common::trace::TraceArgs;
+use clap::Parser;
+
+#[derive(Debug, Parser)]
+#[command(about)]
+struct Args {
+    #[command(flatten)]
+    input: InputArgs,
+
+    #[command(flatten)]
+    params: ParamArgs,
+
+    #[command(flatten)]
+    trace: TraceArgs,
+
+    /// Solve the input as it's read rather than reading it all first, for very large inputs
+    #[arg(long, conflicts_with = "params")]
+    stream: bool,
+}
+
+fn main() -> Result<()> {
+    let args = params::parse_args::<Args, Day7>();
+    let _trace = args.trace.start("day7")?;
+
+    if args.stream {
+        let report = stream::solve::<Day7>(&mut args.input.open(7)?, &args.params.parts())?;
+        for part in &report.parts {
+            if let Some(answer) = &part.answer {
+                println!("Part {}: {}", part.part, answer);
             }
         }
-
-        EvaluatedHand { hand, counts }
-    }
-}
-
-impl PartialEq<Self> for EvaluatedHand<'_> {
-    fn eq(&self, other: &Self) -> bool {
-        self.hand.hand.eq(&other.hand.hand)
+        return Ok(());
     }
-}
-
-impl PartialOrd<Self> for EvaluatedHand<'_> {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
-impl Eq for EvaluatedHand<'_> {}
 
-impl Ord for EvaluatedHand<'_> {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.counts
-            .cmp(&other.counts)
-            .then(self.hand.hand.cmp(&other.hand.hand))
-    }
-}
-
-fn main() {
-    let input_s = fs::read_to_string("inputs/day7.txt").unwrap();
-    let input = parse_input(&input_s).unwrap();
-    println!("Part one: {}", part_one(&input));
-    println!("Part two: {}", part_two(input));
-}
-
-fn part_one(input: &Vec<Hand>) -> i64 {
-    score(input)
-}
-
-fn part_two(mut input: Vec<Hand>) -> i64 {
-    for hand in &mut input {
-        for card in &mut hand.hand {
-            if *card == 11 {
-                *card = 0;
-            }
+    let input = args.input.map(7)?;
+    for part in args.params.parts() {
+        if let Some(answer) = aoc_common::run::<Day7>(&input, part, &args.params.params)? {
+            println!("Part {}: {}", part, answer);
         }
     }
-    score(&input)
-}
-
-fn score(input: &Vec<Hand>) -> i64 {
-    let mut hands: Vec<EvaluatedHand> = input.iter().map(|h| EvaluatedHand::evaluate(h)).collect();
-    hands.sort();
-    hands
-        .iter()
-        .enumerate()
-        .map(|(rank, hand)| (rank + 1) as i64 * hand.hand.bid)
-        .sum()
-}
-
-fn parse_input(input: &str) -> Result<Vec<Hand>> {
-    input
-        .split('\n')
-        .filter(|line| !line.is_empty())
-        .map(|line| {
-            let (hand, bid) = line
-                .split_whitespace()
-                .next_tuple()
-                .ok_or_else(|| anyhow!("Short input line: {}", line))?;
-            Hand::parse(hand, bid)
-        })
-        .collect()
+    Ok(())
 }
@@ -0,0 +1,9 @@
+use aoc23_8::Day8;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn bench(c: &mut Criterion) {
+    aoc_common::bench::solution::<Day8>(c, 8);
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -0,0 +1,87 @@
+//! Random desert maps. `size` is the length of the directions, up to 100.
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::HashSet;
+
+const MULTIPLES: [usize; 8] = [2, 3, 5, 7, 11, 13, 17, 19];
+
+/// Maps with the shape of the real ones: from `AAA` and a few other nodes ending in `A`, the
+/// directions lead along a chain to a node ending in `Z` in a distinct prime number of passes
+/// through them. That node's exits are those of the start, so the walk then repeats.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let length = size.clamp(2, 100);
+    let directions: String = (0..length)
+        .map(|_| if rng.gen() { 'L' } else { 'R' })
+        .collect();
+
+    let mut used = HashSet::from(["AAA".to_string(), "ZZZ".to_string()]);
+
+    let ghosts = rng.gen_range(1..=6);
+    let chains: Vec<Vec<String>> = MULTIPLES
+        .choose_multiple(rng, ghosts)
+        .enumerate()
+        .map(|(ghost, &multiple)| {
+            let steps = multiple * length;
+            let (start, end) = if ghost == 0 {
+                ("AAA".to_string(), "ZZZ".to_string())
+            } else {
+                (
+                    label(rng, &mut used, Some('A')),
+                    label(rng, &mut used, Some('Z')),
+                )
+            };
+            let mut chain = vec![start];
+            chain.extend((1..steps).map(|_| label(rng, &mut used, None)));
+            chain.push(end);
+            chain
+        })
+        .collect();
+    let nodes: Vec<&String> = chains.iter().flatten().collect();
+
+    let mut lines = Vec::new();
+    for chain in &chains {
+        let steps = chain.len() - 1;
+        let mut exits = Vec::new();
+        for (step, pair) in chain.windows(2).enumerate() {
+            let other = nodes.choose(rng).unwrap();
+            let exit = if directions.as_bytes()[step % length] == b'L' {
+                (&pair[1], *other)
+            } else {
+                (*other, &pair[1])
+            };
+            exits.push(exit);
+            lines.push(format!("{} = ({}, {})", pair[0], exit.0, exit.1));
+        }
+        lines.push(format!(
+            "{} = ({}, {})",
+            chain[steps], exits[0].0, exits[0].1
+        ));
+    }
+    lines.shuffle(rng);
+    format!("{}\n\n{}\n", directions, lines.join("\n"))
+}
+
+/// A new three-letter label ending in `last`, or in anything but A or Z, since only starts end in
+/// A and only ends in Z.
+fn label(rng: &mut impl Rng, used: &mut HashSet<String>, last: Option<char>) -> String {
+    loop {
+        let mut letters: String = (0..2).map(|_| rng.gen_range('A'..='Z')).collect();
+        letters.push(last.unwrap_or_else(|| rng.gen_range('B'..='Y')));
+        if used.insert(letters.clone()) {
+            return letters;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day8;
+    use aoc_common::generate::exercise;
+
+    #[test]
+    fn generated_inputs_solve() {
+        exercise::<Day8>(generate, 20, 50);
+    }
+}
@@ -0,0 +1,265 @@
+use anyhow::Result;
+use aoc_common::cycle::{self, Cycle};
+use aoc_common::parse::{lines, one_of, parse_all, tag, IResult};
+use aoc_common::{Solution, Turn};
+use nom::character::complete::{alpha1, multispace0, space0};
+use nom::combinator::{complete, map};
+use nom::multi::many1;
+use nom::sequence::{delimited, separated_pair, terminated};
+use num::integer::{lcm, ExtendedGcd};
+use std::collections::HashMap;
+use std::iter;
+
+#[cfg(any(test, feature = "generate"))]
+pub mod generate;
+
+pub type NodeLabel = String;
+
+#[derive(Debug)]
+pub struct Node {
+    pub left: NodeLabel,
+    pub right: NodeLabel,
+}
+
+#[derive(Debug)]
+pub struct Input {
+    pub directions: Vec<Turn>,
+    pub graph: HashMap<NodeLabel, Node>,
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Input;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_all(input, parse_input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        part_two(input)
+    }
+}
+
+fn part_one(input: &Input) -> usize {
+    let mut current_node = "AAA";
+    let mut count: usize = 0;
+    while !current_node.eq("ZZZ") {
+        let direction = &input.directions[count % input.directions.len()];
+        current_node = advance_node(input, current_node, direction);
+        count += 1;
+    }
+    count
+}
+
+pub fn advance_node<'a>(input: &'a Input, current: &str, direction: &Turn) -> &'a str {
+    let node = input.graph.get(current).unwrap();
+    match direction {
+        Turn::Left => &node.left,
+        Turn::Right => &node.right,
+    }
+}
+
+fn part_two(input: &Input) -> usize {
+    let ghosts: Vec<Ghost> = input
+        .graph
+        .keys()
+        .filter(|label| label.ends_with('A'))
+        .map(|start| ghost(input, start))
+        .collect();
+    first_common_end(&ghosts).expect("the ghosts are never all at an end together")
+}
+
+/// When a ghost walking from one of the start nodes is on a node ending in `Z`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ghost {
+    /// The cycle in the ghost's states: its node and how far it is through the directions.
+    pub cycle: Cycle,
+    /// The steps, before the end of the cycle's first loop, after which the ghost is at an end.
+    pub ends: Vec<usize>,
+}
+
+impl Ghost {
+    /// Whether the ghost is at an end after `steps` steps.
+    pub fn at_end(&self, steps: usize) -> bool {
+        self.ends.binary_search(&self.cycle.reduce(steps)).is_ok()
+    }
+}
+
+/// Follow the directions from `start` until the ghost repeats a state.
+pub fn ghost(input: &Input, start: &str) -> Ghost {
+    let len = input.directions.len();
+    let states = iter::successors(Some((start, 0)), |&(node, i)| {
+        Some((
+            advance_node(input, node, &input.directions[i]),
+            (i + 1) % len,
+        ))
+    });
+    let history = cycle::detect(states).expect("there are finitely many states");
+    let ends = history
+        .states
+        .iter()
+        .enumerate()
+        .filter(|(_, (node, _))| node.ends_with('Z'))
+        .map(|(steps, _)| steps)
+        .collect();
+    Ghost {
+        cycle: history.cycle,
+        ends,
+    }
+}
+
+/// The fewest steps, at least one, after which every ghost is at an end.
+///
+/// Up to the longest of their prefixes, that's checked step by step. After it, each ghost is at
+/// an end on the steps that are one of its ends modulo its period, and those are combined by
+/// the Chinese remainder theorem.
+pub fn first_common_end(ghosts: &[Ghost]) -> Option<usize> {
+    let prefix = ghosts.iter().map(|g| g.cycle.prefix).max().unwrap_or(0);
+    if let Some(steps) = (1..prefix).find(|&steps| ghosts.iter().all(|g| g.at_end(steps))) {
+        return Some(steps);
+    }
+
+    // Every combination of remainders, as (remainder, modulus) pairs.
+    let mut congruences = vec![(0, 1)];
+    for ghost in ghosts {
+        let period = ghost.cycle.period as i128;
+        let remainders: Vec<i128> = ghost
+            .ends
+            .iter()
+            .filter(|&&end| end >= ghost.cycle.prefix)
+            .map(|&end| end as i128 % period)
+            .collect();
+        congruences = congruences
+            .iter()
+            .flat_map(|&(a, m)| remainders.iter().filter_map(move |&b| crt(a, m, b, period)))
+            .collect();
+    }
+    let start = prefix.max(1) as i128;
+    congruences
+        .into_iter()
+        .map(|(r, m)| r + (start - r + m - 1).max(0) / m * m)
+        .min()
+        .map(|steps| steps as usize)
+}
+
+/// The `x` with `x ≡ a (mod m)` and `x ≡ b (mod n)`, as a remainder modulo `lcm(m, n)`, if
+/// there is one.
+fn crt(a: i128, m: i128, b: i128, n: i128) -> Option<(i128, i128)> {
+    let ExtendedGcd { gcd, x, .. } = num::Integer::extended_gcd(&m, &n);
+    if (b - a) % gcd != 0 {
+        return None;
+    }
+    let modulus = lcm(m, n);
+    // m * x ≡ gcd (mod n), so stepping a by m * x * (b - a) / gcd reaches b.
+    let k = ((b - a) / gcd * x).rem_euclid(n / gcd);
+    Some(((a + m * k).rem_euclid(modulus), modulus))
+}
+
+/// Follow the directions from `start` to the first node ending in `Z`, returning it and the
+/// number of steps taken.
+pub fn path_to_end<'a>(start: &'a str, input: &'a Input) -> (&'a str, usize) {
+    let mut count: usize = 0;
+    let mut current_node = start;
+    loop {
+        let direction = &input.directions[count % input.directions.len()];
+        current_node = advance_node(input, current_node, direction);
+        count += 1;
+        if current_node.ends_with('Z') {
+            return (current_node, count);
+        }
+    }
+}
+
+fn parse_input(text: &str) -> IResult<&str, Input> {
+    let (text, (directions, nodes)) =
+        complete(separated_pair(directions, multispace0, nodelist))(text)?;
+    let mut graph: HashMap<NodeLabel, Node> = HashMap::new();
+
+    for (label, node) in nodes {
+        graph.insert(label, node);
+    }
+
+    Ok((text, Input { directions, graph }))
+}
+
+fn directions(text: &str) -> IResult<&str, Vec<Turn>> {
+    terminated(
+        many1(map(one_of("LR"), |c| match c {
+            'L' => Turn::Left,
+            'R' => Turn::Right,
+            _ => panic!("Invalid input: {}", c),
+        })),
+        multispace0,
+    )(text)
+}
+
+fn nodelist(text: &str) -> IResult<&str, Vec<(NodeLabel, Node)>> {
+    terminated(lines(node), multispace0)(text)
+}
+
+fn node(text: &str) -> IResult<&str, (NodeLabel, Node)> {
+    separated_pair(nodelabel, delimited(space0, tag("="), space0), nodedata)(text)
+}
+
+fn nodedata(text: &str) -> IResult<&str, Node> {
+    map(
+        delimited(
+            tag("("),
+            separated_pair(nodelabel, delimited(space0, tag(","), space0), nodelabel),
+            tag(")"),
+        ),
+        |(left, right)| Node { left, right },
+    )(text)
+}
+
+fn nodelabel(text: &str) -> IResult<&str, NodeLabel> {
+    map(alpha1, |s: &str| -> NodeLabel { s.to_string() })(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ghost(prefix: usize, period: usize, ends: &[usize]) -> Ghost {
+        Ghost {
+            cycle: Cycle { prefix, period },
+            ends: ends.to_vec(),
+        }
+    }
+
+    #[test]
+    fn ghosts_in_step() {
+        let input = Day8::parse(
+            "LR\n\nQQA = (QQB, XXX)\nQQB = (XXX, QQZ)\nQQZ = (QQB, XXX)\nRRA = (RRB, XXX)\n\
+             RRB = (RRC, RRC)\nRRC = (RRZ, RRZ)\nRRZ = (RRB, RRB)\nXXX = (XXX, XXX)\n",
+        )
+        .unwrap();
+        assert_eq!(super::ghost(&input, "QQA"), ghost(1, 2, &[2]));
+        assert_eq!(part_two(&input), 6);
+    }
+
+    #[test]
+    fn ends_off_the_loop_boundary() {
+        // At an end on steps 3, 7, 11, ... and on steps 1, 5, 7, 11, ...
+        assert_eq!(
+            first_common_end(&[ghost(1, 4, &[3]), ghost(0, 6, &[1, 5])]),
+            Some(7)
+        );
+        // Together at an end only before one of them starts looping.
+        assert_eq!(
+            first_common_end(&[ghost(3, 2, &[2]), ghost(0, 5, &[2])]),
+            Some(2)
+        );
+        assert_eq!(
+            first_common_end(&[ghost(0, 2, &[0]), ghost(0, 2, &[1])]),
+            None
+        );
+    }
+}
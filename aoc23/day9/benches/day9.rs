@@ -0,0 +1,9 @@
+use aoc23_9::Day9;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn bench(c: &mut Criterion) {
+    aoc_common::bench::solution::<Day9>(c, 9);
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -0,0 +1,43 @@
+//! Random OASIS reports. `size` is the number of histories.
+
+use rand::Rng;
+
+/// Histories of 21 values of a polynomial of degree at most five with small coefficients, so
+/// that repeated differences always reach zero.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let mut report = String::new();
+    for _ in 0..size.max(1) {
+        let coefficients: Vec<i64> = (0..=rng.gen_range(0..=5))
+            .map(|_| rng.gen_range(-9..=9))
+            .collect();
+        let history: Vec<String> = (0..21i64)
+            .map(|x| {
+                coefficients
+                    .iter()
+                    .rev()
+                    .fold(0, |value, c| value * x + c)
+                    .to_string()
+            })
+            .collect();
+        report.push_str(&history.join(" "));
+        report.push('\n');
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day9;
+    use aoc_common::generate::{exercise, exercise_streaming};
+
+    #[test]
+    fn generated_inputs_solve() {
+        exercise::<Day9>(generate, 20, 50);
+    }
+
+    #[test]
+    fn generated_inputs_stream() {
+        exercise_streaming::<Day9>(generate, 20, 50);
+    }
+}
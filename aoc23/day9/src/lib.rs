@@ -0,0 +1,102 @@
+use anyhow::Result;
+use aoc_common::parse::{lines, parse_all, IResult};
+use aoc_common::stream::Streaming;
+use aoc_common::Solution;
+use nom::character::complete::{i64 as pi64, space1};
+use nom::multi::separated_list1;
+
+#[cfg(any(test, feature = "generate"))]
+pub mod generate;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<Vec<i64>>;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_all(input, parse_input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        part_two(input)
+    }
+}
+
+impl Streaming for Day9 {
+    /// The sums of each part's predictions so far.
+    type State = (i64, i64);
+
+    fn feed(state: &mut Self::State, line: &str) -> Result<()> {
+        for history in parse_all(line, parse_input)? {
+            state.0 += prediction(&history);
+            state.1 += prediction2(&history);
+        }
+        Ok(())
+    }
+
+    fn finish_one(state: &Self::State) -> Self::PartOne {
+        state.0
+    }
+
+    fn finish_two(state: &Self::State) -> Self::PartTwo {
+        state.1
+    }
+}
+
+fn part_one(input: &[Vec<i64>]) -> i64 {
+    input.iter().map(|v| prediction(v)).sum()
+}
+
+fn part_two(input: &[Vec<i64>]) -> i64 {
+    input.iter().map(|v| prediction2(v)).sum()
+}
+
+/// The next value of the sequence, extrapolated from its differences.
+pub fn prediction(input: &[i64]) -> i64 {
+    let mut seq = input.to_owned();
+    let mut result: i64 = *seq.last().unwrap();
+    let mut all_zero: bool = false;
+    while !all_zero {
+        all_zero = true;
+        for idx in 1..seq.len() {
+            seq[idx - 1] = seq[idx] - seq[idx - 1];
+            if seq[idx - 1] != 0 {
+                all_zero = false;
+            }
+        }
+        seq.pop();
+        result += seq.last().unwrap();
+    }
+    result
+}
+
+/// The value before the start of the sequence, extrapolated from its differences.
+pub fn prediction2(input: &[i64]) -> i64 {
+    let mut seq = input.to_owned();
+    let mut result: i64 = *seq.first().unwrap();
+    let mut sign = -1;
+    let mut all_zero: bool = false;
+    while !all_zero {
+        all_zero = true;
+        for idx in 1..seq.len() {
+            seq[idx - 1] = seq[idx] - seq[idx - 1];
+            if seq[idx - 1] != 0 {
+                all_zero = false;
+            }
+        }
+        seq.pop();
+        result += seq.first().unwrap() * sign;
+        sign *= -1;
+    }
+    result
+}
+
+fn parse_input(input: &str) -> IResult<&str, Vec<Vec<i64>>> {
+    lines(separated_list1(space1, pi64))(input)
+}
@@ -1,61 +1,47 @@
-use nom::character::complete::{i64 as pi64, line_ending, space1};
-use nom::multi::separated_list1;
-use nom::IResult;
-use std::fs;
+use anyhow::Result;
+use aoc23_9::Day9;
+use aoc_common::input::InputArgs;
+use aoc_common::params::{self, ParamArgs};
+use aoc_common::stream;
+use aoc_common::trace::TraceArgs;
+use clap::Parser;
 
-fn main() {
-    let input_s = fs::read_to_string("inputs/day9.txt").unwrap();
-    let (_, input) = parse_input(&input_s).unwrap();
-    println!("Part one: {}", part_one(&input));
-    println!("Part two: {}", part_two(&input));
-}
+#[derive(Debug, Parser)]
+#[command(about)]
+struct Args {
+    #[command(flatten)]
+    input: InputArgs,
 
-fn part_one(input: &[Vec<i64>]) -> i64 {
-    input.iter().map(|v| prediction(v)).sum()
-}
+    #[command(flatten)]
+    params: ParamArgs,
 
-fn part_two(input: &[Vec<i64>]) -> i64 {
-    input.iter().map(|v| prediction2(v)).sum()
+    #[command(flatten)]
+    trace: TraceArgs,
+
+    /// Solve the input as it's read rather than reading it all first, for very large inputs
+    #[arg(long, conflicts_with = "params")]
+    stream: bool,
 }
 
-fn prediction(input: &[i64]) -> i64 {
-    let mut seq = input.to_owned();
-    let mut result: i64 = *seq.last().unwrap();
-    let mut all_zero: bool = false;
-    while !all_zero {
-        all_zero = true;
-        for idx in 1..seq.len() {
-            seq[idx - 1] = seq[idx] - seq[idx - 1];
-            if seq[idx - 1] != 0 {
-                all_zero = false;
+fn main() -> Result<()> {
+    let args = params::parse_args::<Args, Day9>();
+    let _trace = args.trace.start("day9")?;
+
+    if args.stream {
+        let report = stream::solve::<Day9>(&mut args.input.open(9)?, &args.params.parts())?;
+        for part in &report.parts {
+            if let Some(answer) = &part.answer {
+                println!("Part {}: {}", part.part, answer);
             }
         }
-        seq.pop();
-        result += seq.last().unwrap();
+        return Ok(());
     }
-    result
-}
 
-fn prediction2(input: &[i64]) -> i64 {
-    let mut seq = input.to_owned();
-    let mut result: i64 = *seq.first().unwrap();
-    let mut sign = -1;
-    let mut all_zero: bool = false;
-    while !all_zero {
-        all_zero = true;
-        for idx in 1..seq.len() {
-            seq[idx - 1] = seq[idx] - seq[idx - 1];
-            if seq[idx - 1] != 0 {
-                all_zero = false;
-            }
+    let input = args.input.map(9)?;
+    for part in args.params.parts() {
+        if let Some(answer) = aoc_common::run::<Day9>(&input, part, &args.params.params)? {
+            println!("Part {}: {}", part, answer);
         }
-        seq.pop();
-        result += seq.first().unwrap() * sign;
-        sign *= -1;
     }
-    result
-}
-
-fn parse_input(input: &str) -> IResult<&str, Vec<Vec<i64>>> {
-    separated_list1(line_ending, separated_list1(space1, pi64))(input)
+    Ok(())
 }
@@ -0,0 +1,36 @@
+//! Build day 1's C solution with the system C compiler (`$CC`, or cc's default), for the
+//! parity tests to run. Without a C compiler, the C solution is left out of the tests, rather
+//! than failing the build for the whole workspace.
+
+use std::env;
+use std::path::PathBuf;
+
+fn main() {
+    let source = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap()).join("../day1/main.c");
+    let program = PathBuf::from(env::var("OUT_DIR").unwrap()).join("day1");
+    println!("cargo:rerun-if-changed={}", source.display());
+    println!("cargo:rerun-if-env-changed=CC");
+    println!("cargo:rustc-check-cfg=cfg(day1_c)");
+
+    let status = cc::Build::new()
+        .opt_level(2)
+        .get_compiler()
+        .to_command()
+        .arg("-o")
+        .arg(&program)
+        .arg(&source)
+        .status();
+    let failure = match status {
+        Ok(status) if status.success() => {
+            println!("cargo:rustc-cfg=day1_c");
+            println!("cargo:rustc-env=DAY1_C={}", program.display());
+            return;
+        }
+        Ok(status) => format!("compiling {} failed, {}", source.display(), status),
+        Err(err) => format!("couldn't run the C compiler: {}", err),
+    };
+    println!(
+        "cargo:warning=Leaving the C solution out of the parity tests: {}",
+        failure
+    );
+}
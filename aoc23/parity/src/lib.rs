@@ -0,0 +1,238 @@
+//! Checking that day 1's three solutions agree: the Rust one, the C one in `day1/main.c`, and
+//! the Python one in `day1/main.py`.
+//!
+//! The C solution is only compared if the build script could compile it, which needs a C
+//! compiler, and the Python one only if there's a Python interpreter to run it, so the tests
+//! pass with just Rust, comparing fewer solutions.
+//!
+//! The C and Python solutions only solve part two, so that's what's compared. When they
+//! disagree on an input, each line is solved on its own to find the first one they disagree
+//! on.
+
+use anyhow::{anyhow, bail, Context, Result};
+use aoc23_1::Day1;
+use aoc_common::Part;
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::OnceLock;
+use std::{env, process};
+
+/// One of the solutions, as a name and how to solve part two of an input with it.
+#[derive(Debug, Clone, Copy)]
+pub struct Implementation {
+    pub name: &'static str,
+    pub solve: fn(&str) -> Result<String>,
+}
+
+/// The Rust, C and Python solutions, leaving out C if it couldn't be compiled, and Python if
+/// there's no interpreter.
+pub fn implementations() -> Vec<Implementation> {
+    let mut implementations = vec![Implementation {
+        name: "Rust",
+        solve: rust,
+    }];
+    #[cfg(day1_c)]
+    implementations.push(Implementation {
+        name: "C",
+        solve: c,
+    });
+    if interpreter().is_some() {
+        implementations.push(Implementation {
+            name: "Python",
+            solve: python,
+        });
+    }
+    implementations
+}
+
+/// Part two by the Rust solution.
+pub fn rust(input: &str) -> Result<String> {
+    aoc_common::run::<Day1>(input, Part::Two, &[])?.ok_or_else(|| anyhow!("no answer"))
+}
+
+/// Part two by the C solution, built by the build script, which reads the input from stdin
+/// and prints `Result: ANSWER`.
+#[cfg(day1_c)]
+pub fn c(input: &str) -> Result<String> {
+    let output = run(Command::new(env!("DAY1_C")), Some(input))?;
+    output
+        .strip_prefix("Result: ")
+        .map(|answer| answer.trim().to_string())
+        .ok_or_else(|| anyhow!("unexpected output: {}", output))
+}
+
+/// Part two by the Python solution, run with [`interpreter`]. It reads the input from
+/// `input.txt` in its working directory, so it runs in a directory of its own.
+pub fn python(input: &str) -> Result<String> {
+    let script = Path::new(env!("CARGO_MANIFEST_DIR")).join("../day1/main.py");
+    let dir = scratch_dir()?;
+    fs::write(dir.join("input.txt"), input)?;
+    let interpreter = interpreter().ok_or_else(|| anyhow!("no Python interpreter"))?;
+    let mut command = Command::new(interpreter);
+    command.arg(script).current_dir(&dir);
+    let output = run(command, None);
+    fs::remove_dir_all(&dir)?;
+    Ok(output?.trim().to_string())
+}
+
+/// A new empty directory for one run.
+fn scratch_dir() -> Result<PathBuf> {
+    static RUNS: AtomicUsize = AtomicUsize::new(0);
+    let run = RUNS.fetch_add(1, Ordering::Relaxed);
+    let dir = env::temp_dir().join(format!("aoc23-parity-{}-{}", process::id(), run));
+    fs::create_dir_all(&dir).with_context(|| format!("creating {}", dir.display()))?;
+    Ok(dir)
+}
+
+/// Run `command`, with `stdin` as its input if given, returning what it prints.
+/// The Python interpreter, `$PYTHON` or `python3`, if it runs. It's looked for once.
+pub fn interpreter() -> Option<&'static str> {
+    static INTERPRETER: OnceLock<Option<String>> = OnceLock::new();
+    INTERPRETER
+        .get_or_init(|| {
+            let interpreter = env::var("PYTHON").unwrap_or_else(|_| "python3".to_string());
+            let mut command = Command::new(&interpreter);
+            command.arg("--version");
+            run(command, None).ok().map(|_| interpreter)
+        })
+        .as_deref()
+}
+
+fn run(mut command: Command, stdin: Option<&str>) -> Result<String> {
+    let program = command.get_program().to_string_lossy().into_owned();
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("running {}", program))?;
+    // Dropping stdin closes it, so the program sees the end of its input.
+    let mut pipe = child.stdin.take().unwrap();
+    pipe.write_all(stdin.unwrap_or_default().as_bytes())?;
+    drop(pipe);
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        bail!(
+            "{} failed, {}: {}",
+            program,
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+/// Where the solutions disagree on an input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Divergence {
+    /// The first line of the input that they disagree on, numbered from 1, with its text. None
+    /// if they agree on every line on its own.
+    pub line: Option<(usize, String)>,
+    /// Each solution's answer or error, on that line or else on the whole input.
+    pub answers: Vec<(&'static str, String)>,
+}
+
+impl Display for Divergence {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.line {
+            Some((number, text)) => write!(f, "first disagree on line {}, {:?}:", number, text)?,
+            None => write!(f, "agree on each line, but not on the whole input:")?,
+        }
+        for (name, answer) in &self.answers {
+            write!(f, "\n  {}: {}", name, answer)?;
+        }
+        Ok(())
+    }
+}
+
+/// Every solution's answer to `input`, or the error it gave, if they don't all agree.
+fn disagreement(
+    input: &str,
+    implementations: &[Implementation],
+) -> Option<Vec<(&'static str, String)>> {
+    let answers: Vec<_> = implementations
+        .iter()
+        .map(|i| {
+            let answer = (i.solve)(input).unwrap_or_else(|err| format!("error: {:#}", err));
+            (i.name, answer)
+        })
+        .collect();
+    let agree = answers.windows(2).all(|pair| pair[0].1 == pair[1].1);
+    (!agree).then_some(answers)
+}
+
+/// Check that `implementations` agree on `input`, finding the first line they disagree on if
+/// not.
+pub fn compare(input: &str, implementations: &[Implementation]) -> Result<(), Divergence> {
+    let Some(answers) = disagreement(input, implementations) else {
+        return Ok(());
+    };
+    for (i, line) in input.lines().enumerate() {
+        if let Some(answers) = disagreement(&format!("{}\n", line), implementations) {
+            return Err(Divergence {
+                line: Some((i + 1, line.to_string())),
+                answers,
+            });
+        }
+    }
+    Err(Divergence {
+        line: None,
+        answers,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Result<String> {
+        Ok(input
+            .chars()
+            .filter(char::is_ascii_digit)
+            .count()
+            .to_string())
+    }
+
+    fn digits_but_seven(input: &str) -> Result<String> {
+        Ok(input
+            .chars()
+            .filter(|&c| c.is_ascii_digit() && c != '7')
+            .count()
+            .to_string())
+    }
+
+    fn failing(_: &str) -> Result<String> {
+        bail!("no")
+    }
+
+    #[test]
+    fn finds_the_first_divergence() {
+        let digits = Implementation {
+            name: "digits",
+            solve: digits,
+        };
+        let but_seven = Implementation {
+            name: "but seven",
+            solve: digits_but_seven,
+        };
+        assert_eq!(compare("12\n34\n", &[digits, but_seven]), Ok(()));
+        assert_eq!(
+            compare("12\n34\n5\n77\n", &[digits, but_seven]),
+            Err(Divergence {
+                line: Some((4, "77".to_string())),
+                answers: vec![("digits", "2".to_string()), ("but seven", "0".to_string())],
+            })
+        );
+
+        let failing = Implementation {
+            name: "failing",
+            solve: failing,
+        };
+        let divergence = compare("1\n", &[digits, failing]).unwrap_err();
+        assert_eq!(divergence.answers[1], ("failing", "error: no".to_string()));
+    }
+}
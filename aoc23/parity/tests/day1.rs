@@ -0,0 +1,29 @@
+use aoc23_1::generate::generate;
+use aoc23_parity::{compare, implementations};
+use aoc_common::generate::seeded;
+use std::fs;
+use std::path::Path;
+
+#[test]
+fn examples_agree() {
+    let examples = Path::new(env!("CARGO_MANIFEST_DIR")).join("../examples");
+    for name in ["day1.txt", "day1_2.txt"] {
+        let input = fs::read_to_string(examples.join(name)).unwrap();
+        if let Err(divergence) = compare(&input, &implementations()) {
+            panic!("On {}, the solutions {}", name, divergence);
+        }
+    }
+}
+
+#[test]
+fn generated_inputs_agree() {
+    for seed in 0..20 {
+        let input = seeded(generate, seed, 50);
+        if let Err(divergence) = compare(&input, &implementations()) {
+            panic!(
+                "On the input from seed {}, the solutions {}",
+                seed, divergence
+            );
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use std::fmt::Write;
+use std::path::Path;
+use std::{env, fs};
+
+const ANSWERS: &str = "../examples/answers.toml";
+
+// Generate one test per example answer in the manifest, for src/examples.rs to include.
+fn main() {
+    println!("cargo:rerun-if-changed={}", ANSWERS);
+    let manifest: toml::Table = fs::read_to_string(ANSWERS)
+        .expect("reading examples manifest")
+        .parse()
+        .expect("parsing examples manifest");
+
+    let mut tests = String::new();
+    for (name, answers) in &manifest {
+        let day: u8 = name
+            .strip_prefix("day")
+            .and_then(|rest| rest.split('_').next())
+            .and_then(|day| day.parse().ok())
+            .unwrap_or_else(|| panic!("{}: expected a name like day10 or day10_2", name));
+        let answers = answers
+            .as_table()
+            .unwrap_or_else(|| panic!("{}: expected a table", name));
+        for (key, answer) in answers {
+            let part = match key.as_str() {
+                "part_one" => "One",
+                "part_two" => "Two",
+                _ => panic!("{}: unknown key {}", name, key),
+            };
+            let answer = match answer {
+                toml::Value::Integer(n) => n.to_string(),
+                toml::Value::String(s) => s.clone(),
+                _ => panic!("{}.{}: expected an integer or string", name, key),
+            };
+            writeln!(
+                tests,
+                "#[test]\nfn {name}_{key}() {{\n    check({day}, \"{name}.txt\", Part::{part}, {answer:?});\n}}\n"
+            )
+            .unwrap();
+        }
+    }
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("examples.rs");
+    fs::write(out, tests).unwrap();
+}
@@ -0,0 +1,181 @@
+use anyhow::Result;
+use aoc_common::generate::Generator;
+use aoc_common::params::{Override, Param};
+use aoc_common::{stream, Part, Report, Solution};
+use std::io::BufRead;
+
+pub type Runner = fn(&str, &[Part], &[Override]) -> Result<Report>;
+
+/// Solves a day's input as it's read.
+pub type Streamer = fn(&mut dyn BufRead, &[Part]) -> Result<Report>;
+
+/// Every solved day, with its solver, its input generator and its parameters.
+pub const DAYS: &[(u8, Runner, Generator, &[Param])] = &[
+    (
+        1,
+        aoc_common::solve::<aoc23_1::Day1>,
+        aoc23_1::generate::generate,
+        aoc23_1::Day1::PARAMS,
+    ),
+    (
+        2,
+        aoc_common::solve::<aoc23_2::Day2>,
+        aoc23_2::generate::generate,
+        aoc23_2::Day2::PARAMS,
+    ),
+    (
+        3,
+        aoc_common::solve::<aoc23_3::Day3>,
+        aoc23_3::generate::generate,
+        aoc23_3::Day3::PARAMS,
+    ),
+    (
+        4,
+        aoc_common::solve::<aoc23_4::Day4>,
+        aoc23_4::generate::generate,
+        aoc23_4::Day4::PARAMS,
+    ),
+    (
+        5,
+        aoc_common::solve::<aoc23_5::Day5>,
+        aoc23_5::generate::generate,
+        aoc23_5::Day5::PARAMS,
+    ),
+    (
+        6,
+        aoc_common::solve::<aoc23_6::Day6>,
+        aoc23_6::generate::generate,
+        aoc23_6::Day6::PARAMS,
+    ),
+    (
+        7,
+        aoc_common::solve::<aoc23_7::Day7>,
+        aoc23_7::generate::generate,
+        aoc23_7::Day7::PARAMS,
+    ),
+    (
+        8,
+        aoc_common::solve::<aoc23_8::Day8>,
+        aoc23_8::generate::generate,
+        aoc23_8::Day8::PARAMS,
+    ),
+    (
+        9,
+        aoc_common::solve::<aoc23_9::Day9>,
+        aoc23_9::generate::generate,
+        aoc23_9::Day9::PARAMS,
+    ),
+    (
+        10,
+        aoc_common::solve::<aoc23_10::Day10>,
+        aoc23_10::generate::generate,
+        aoc23_10::Day10::PARAMS,
+    ),
+    (
+        11,
+        aoc_common::solve::<aoc23_11::Day11>,
+        aoc23_11::generate::generate,
+        aoc23_11::Day11::PARAMS,
+    ),
+    (
+        12,
+        aoc_common::solve::<aoc23_12::Day12>,
+        aoc23_12::generate::generate,
+        aoc23_12::Day12::PARAMS,
+    ),
+    (
+        13,
+        aoc_common::solve::<aoc23_13::Day13>,
+        aoc23_13::generate::generate,
+        aoc23_13::Day13::PARAMS,
+    ),
+    (
+        14,
+        aoc_common::solve::<aoc23_14::Day14>,
+        aoc23_14::generate::generate,
+        aoc23_14::Day14::PARAMS,
+    ),
+    (
+        15,
+        aoc_common::solve::<aoc23_15::Day15>,
+        aoc23_15::generate::generate,
+        aoc23_15::Day15::PARAMS,
+    ),
+    (
+        16,
+        aoc_common::solve::<aoc23_16::Day16>,
+        aoc23_16::generate::generate,
+        aoc23_16::Day16::PARAMS,
+    ),
+    (
+        17,
+        aoc_common::solve::<aoc23_17::Day17>,
+        aoc23_17::generate::generate,
+        aoc23_17::Day17::PARAMS,
+    ),
+    (
+        18,
+        aoc_common::solve::<aoc23_18::Day18>,
+        aoc23_18::generate::generate,
+        aoc23_18::Day18::PARAMS,
+    ),
+    (
+        19,
+        aoc_common::solve::<aoc23_19::Day19>,
+        aoc23_19::generate::generate,
+        aoc23_19::Day19::PARAMS,
+    ),
+    (
+        20,
+        aoc_common::solve::<aoc23_20::Day20>,
+        aoc23_20::generate::generate,
+        aoc23_20::Day20::PARAMS,
+    ),
+    (
+        21,
+        aoc_common::solve::<aoc23_21::Day21>,
+        aoc23_21::generate::generate,
+        aoc23_21::Day21::PARAMS,
+    ),
+    (
+        22,
+        aoc_common::solve::<aoc23_22::Day22>,
+        aoc23_22::generate::generate,
+        aoc23_22::Day22::PARAMS,
+    ),
+    (
+        23,
+        aoc_common::solve::<aoc23_23::Day23>,
+        aoc23_23::generate::generate,
+        aoc23_23::Day23::PARAMS,
+    ),
+];
+
+pub fn find(day: u8) -> Option<Runner> {
+    DAYS.iter()
+        .find(|(number, _, _, _)| *number == day)
+        .map(|(_, runner, _, _)| *runner)
+}
+
+pub fn generator(day: u8) -> Option<Generator> {
+    DAYS.iter()
+        .find(|(number, _, _, _)| *number == day)
+        .map(|(_, _, generator, _)| *generator)
+}
+
+/// The days that can solve their input as it's read, for inputs too big to hold in memory.
+pub const STREAMING: &[(u8, Streamer)] = &[
+    (1, stream::solve::<aoc23_1::Day1>),
+    (4, stream::solve::<aoc23_4::Day4>),
+    (7, stream::solve::<aoc23_7::Day7>),
+    (9, stream::solve::<aoc23_9::Day9>),
+    (12, stream::solve::<aoc23_12::Day12>),
+    (15, stream::solve::<aoc23_15::Day15>),
+];
+
+pub fn streamer(day: u8) -> Option<Streamer> {
+    STREAMING
+        .iter()
+        .find(|(number, _)| *number == day)
+        .map(|(_, streamer)| *streamer)
+}
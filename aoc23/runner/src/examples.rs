@@ -0,0 +1,21 @@
+//! Every day's solver run against the example inputs, checked against `examples/answers.toml`.
+//! The build script generates one test per answer in the manifest.
+
+use crate::days;
+use aoc_common::Part;
+use std::fs;
+
+fn check(day: u8, file: &str, part: Part, expected: &str) {
+    let runner = days::find(day).unwrap_or_else(|| panic!("day {} is not solved", day));
+    let input = fs::read_to_string(format!("../examples/{}", file)).unwrap();
+    let report = runner(&input, &[part], &[]).unwrap();
+    assert_eq!(
+        report.answer(part),
+        Some(expected),
+        "{} part {}",
+        file,
+        part
+    );
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
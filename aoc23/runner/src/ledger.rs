@@ -0,0 +1,133 @@
+//! The answer ledger: answers to our own puzzle inputs that the site has accepted, kept in
+//! `answers.toml` alongside the inputs, in the same layout as `examples/answers.toml`.
+
+use anyhow::{anyhow, bail, Context, Result};
+use aoc_common::{input, Part};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+/// `answers.toml` in the input directory.
+pub fn path() -> PathBuf {
+    input::dir().join("answers.toml")
+}
+
+const HEADER: &str = "# Accepted answers for the puzzle inputs in this directory, recorded by \
+`aoc23 run --record`\n# and checked by `aoc23 verify`.\n";
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Ledger {
+    answers: BTreeMap<(u8, Part), String>,
+}
+
+impl Ledger {
+    /// Read the ledger at `path`. A missing file is an empty ledger.
+    pub fn load(path: &Path) -> Result<Ledger> {
+        let context = || format!("reading {}", path.display());
+        match fs::read_to_string(path) {
+            Ok(contents) => Ledger::parse(&contents).with_context(context),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(Ledger::default()),
+            Err(err) => Err(err).with_context(context),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, self.to_string()).with_context(|| format!("writing {}", path.display()))
+    }
+
+    /// Record `answer`, returning the answer it replaced, if any.
+    pub fn insert(&mut self, day: u8, part: Part, answer: String) -> Option<String> {
+        self.answers.insert((day, part), answer)
+    }
+
+    /// Every recorded answer, in day and part order.
+    pub fn iter(&self) -> impl Iterator<Item = (u8, Part, &str)> {
+        self.answers
+            .iter()
+            .map(|(&(day, part), answer)| (day, part, answer.as_str()))
+    }
+
+    fn parse(contents: &str) -> Result<Ledger> {
+        let table: toml::Table = contents.parse()?;
+        let mut ledger = Ledger::default();
+        for (name, answers) in &table {
+            let day: u8 = name
+                .strip_prefix("day")
+                .and_then(|day| day.parse().ok())
+                .ok_or_else(|| anyhow!("{}: expected a name like day10", name))?;
+            let answers = answers
+                .as_table()
+                .ok_or_else(|| anyhow!("{}: expected a table", name))?;
+            for (key, answer) in answers {
+                let part = match key.as_str() {
+                    "part_one" => Part::One,
+                    "part_two" => Part::Two,
+                    _ => bail!("{}: unknown key {}", name, key),
+                };
+                let answer = match answer {
+                    toml::Value::Integer(n) => n.to_string(),
+                    toml::Value::String(s) => s.clone(),
+                    _ => bail!("{}.{}: expected an integer or string", name, key),
+                };
+                ledger.insert(day, part, answer);
+            }
+        }
+        Ok(ledger)
+    }
+}
+
+impl std::fmt::Display for Ledger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(HEADER)?;
+        let mut previous = None;
+        for (day, part, answer) in self.iter() {
+            if previous != Some(day) {
+                write!(f, "\n[day{}]\n", day)?;
+                previous = Some(day);
+            }
+            // Numbers are written bare, as in the examples manifest; anything else is quoted.
+            match answer.parse::<i64>() {
+                Ok(n) => writeln!(f, "part_{} = {}", part, n)?,
+                Err(_) => writeln!(f, "part_{} = {:?}", part, answer)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let mut ledger = Ledger::default();
+        ledger.insert(10, Part::Two, "4".to_string());
+        ledger.insert(2, Part::One, "2162".to_string());
+        ledger.insert(10, Part::One, "6864".to_string());
+        ledger.insert(2, Part::Two, "EFGH".to_string());
+
+        let written = ledger.to_string();
+        assert!(written.contains("[day2]\npart_one = 2162\npart_two = \"EFGH\"\n"));
+        assert_eq!(Ledger::parse(&written).unwrap(), ledger);
+        assert_eq!(
+            ledger
+                .iter()
+                .map(|(day, part, _)| (day, part))
+                .collect::<Vec<_>>(),
+            [
+                (2, Part::One),
+                (2, Part::Two),
+                (10, Part::One),
+                (10, Part::Two)
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_keys() {
+        let err = Ledger::parse("[day3]\npart_three = 1\n").unwrap_err();
+        assert_eq!(err.to_string(), "day3: unknown key part_three");
+    }
+}
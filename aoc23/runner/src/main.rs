@@ -0,0 +1,374 @@
+use anyhow::{anyhow, Context, Result};
+use aoc_common::alloc::CountingAlloc;
+use aoc_common::generate;
+use aoc_common::input::{self, InputArgs};
+use aoc_common::params::{self, Override};
+use aoc_common::trace::TraceArgs;
+use aoc_common::{Part, Report};
+use clap::{CommandFactory, FromArgMatches, Parser, Subcommand, ValueEnum};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::process;
+use tracing::info_span;
+
+mod days;
+#[cfg(test)]
+mod examples;
+mod ledger;
+mod scaffold;
+
+use ledger::Ledger;
+
+#[global_allocator]
+static ALLOC: CountingAlloc = CountingAlloc;
+
+#[derive(Debug, Parser)]
+#[command(about = "Advent of Code 2023 solutions")]
+struct Args {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Solve a single day, or every day with --all
+    Run(RunArgs),
+    /// Re-solve every answer in answers.toml in the input directory and report any that changed
+    Verify,
+    /// Print a random input for a day
+    Generate(GenerateArgs),
+    /// Create a crate for a new day from the template and add it to the workspace
+    NewDay(NewDayArgs),
+}
+
+#[derive(Debug, clap::Args)]
+struct RunArgs {
+    /// Day to solve
+    #[arg(required_unless_present = "all", value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+
+    /// Solve every day that has an input file
+    #[arg(long, conflicts_with_all = ["day", "input"])]
+    all: bool,
+
+    /// Only solve this part (1 or 2)
+    #[arg(short, long)]
+    part: Option<Part>,
+
+    #[command(flatten)]
+    input: InputArgs,
+
+    /// Set a parameter of the day's puzzle, for both parts or just one, as listed below
+    #[arg(
+        long = "param",
+        value_name = "[PART.]NAME=VALUE",
+        conflicts_with_all = ["all", "stream", "record"]
+    )]
+    params: Vec<Override>,
+
+    /// Record the answers in answers.toml in the input directory, once the site has accepted them
+    #[arg(long, conflicts_with = "input")]
+    record: bool,
+
+    /// Solve the input as it's read rather than reading it all first, for very large inputs.
+    /// Only some days can
+    #[arg(long, conflicts_with = "all")]
+    stream: bool,
+
+    /// Output format. json adds parse and part timings and allocation counts
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    #[command(flatten)]
+    trace: TraceArgs,
+}
+
+#[derive(Debug, clap::Args)]
+struct GenerateArgs {
+    /// Day to generate an input for
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Seed for the random number generator; the same seed and size give the same input.
+    /// Without one, a random seed is used and printed to stderr
+    #[arg(long)]
+    seed: Option<u64>,
+
+    /// How large an input to generate. What it counts depends on the day
+    #[arg(long, default_value_t = 10)]
+    size: usize,
+}
+
+#[derive(Debug, clap::Args)]
+struct NewDayArgs {
+    /// Day to create
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Workspace to add the day to, created if it doesn't exist, as for a new year's aoc24
+    #[arg(long, default_value = ".")]
+    workspace: PathBuf,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+}
+
+/// One day's report, as output by `run --format json`.
+#[derive(Debug, Serialize)]
+struct DayReport {
+    day: u8,
+    #[serde(flatten)]
+    report: Report,
+}
+
+/// Every day's report, as output by `run --all --format json`.
+#[derive(Debug, Serialize)]
+struct SuiteReport {
+    days: Vec<DayReport>,
+    /// Total time parsing and solving, across every day.
+    time_ns: u128,
+}
+
+fn main() {
+    let command = Args::command().mut_subcommand("run", |run| run.after_help(parameters_help()));
+    let args =
+        Args::from_arg_matches_mut(&mut command.get_matches()).unwrap_or_else(|err| err.exit());
+    let result = match args.command {
+        Command::Run(run) => run_command(&run),
+        Command::Verify => verify_command(),
+        Command::Generate(generate) => generate_command(&generate),
+        Command::NewDay(new_day) => new_day_command(&new_day),
+    };
+    if let Err(err) = result {
+        eprintln!("Error: {:#}", err);
+        process::exit(1);
+    }
+}
+
+fn run_command(args: &RunArgs) -> Result<()> {
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+    let mut ledger = match args.record {
+        true => Some(Ledger::load(&ledger::path())?),
+        false => None,
+    };
+    let _trace = match args.day {
+        Some(day) => args.trace.start(&format!("day{}", day))?,
+        None => args.trace.start("aoc23")?,
+    };
+
+    if args.all {
+        let mut failed = false;
+        let mut reports = Vec::new();
+        for &(day, _, _, _) in days::DAYS {
+            let path = input::default_path(day);
+            if !path.exists() {
+                eprintln!("Day {}: skipped, {} not found", day, path.display());
+                continue;
+            }
+            let result = input::map(&path)
+                .and_then(|input| run_day(day, &input, &parts, &[], ledger.as_mut()));
+            match result {
+                Ok(report) if args.format == Format::Text => {
+                    println!("Day {}:", day);
+                    print_answers(&report, "  ");
+                }
+                Ok(report) => reports.push(DayReport { day, report }),
+                Err(err) => {
+                    eprintln!("Day {}: {:#}", day, err);
+                    failed = true;
+                }
+            }
+        }
+        if args.format == Format::Json {
+            let time_ns = reports
+                .iter()
+                .map(|day| day.report.total_time().as_nanos())
+                .sum();
+            print_json(&SuiteReport {
+                days: reports,
+                time_ns,
+            })?;
+        }
+        save(ledger.as_ref())?;
+        if failed {
+            return Err(anyhow!("some days failed"));
+        }
+        return Ok(());
+    }
+
+    let day = args.day.expect("clap requires a day without --all");
+    let report = if args.stream {
+        let streamer =
+            days::streamer(day).ok_or_else(|| anyhow!("day {} can't stream its input", day))?;
+        let report = info_span!("day", day)
+            .in_scope(|| streamer(&mut *args.input.open(day)?, &parts))
+            .with_context(|| format!("day {}", day))?;
+        record(day, &report, ledger.as_mut());
+        report
+    } else {
+        let input = args.input.map(day)?;
+        run_day(day, &input, &parts, &args.params, ledger.as_mut())?
+    };
+    match args.format {
+        Format::Text => print_answers(&report, ""),
+        Format::Json => print_json(&DayReport { day, report })?,
+    }
+    save(ledger.as_ref())
+}
+
+fn run_day(
+    day: u8,
+    input: &str,
+    parts: &[Part],
+    overrides: &[Override],
+    ledger: Option<&mut Ledger>,
+) -> Result<Report> {
+    let runner = days::find(day).ok_or_else(|| anyhow!("day {} is not solved", day))?;
+    let report = info_span!("day", day)
+        .in_scope(|| runner(input, parts, overrides))
+        .with_context(|| format!("day {}", day))?;
+    record(day, &report, ledger);
+    Ok(report)
+}
+
+/// Put `report`'s answers in `ledger`, if answers are being recorded.
+fn record(day: u8, report: &Report, ledger: Option<&mut Ledger>) {
+    if let Some(ledger) = ledger {
+        for part in &report.parts {
+            if let Some(answer) = &part.answer {
+                if let Some(old) = ledger.insert(day, part.part, answer.clone()) {
+                    if &old != answer {
+                        eprintln!(
+                            "Day {} part {}: replaced recorded answer {}",
+                            day, part.part, old
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Every day's parameters, for `run --help`.
+fn parameters_help() -> String {
+    let mut help = format!("{}\n", params::HELP_HEADING);
+    for &(day, _, _, declared) in days::DAYS {
+        if !declared.is_empty() {
+            help.push_str(&format!("  Day {}:\n", day));
+            help.push_str(&params::list(declared, "    "));
+        }
+    }
+    help
+}
+
+fn print_answers(report: &Report, indent: &str) {
+    for part in &report.parts {
+        match &part.answer {
+            Some(answer) => println!("{}Part {}: {}", indent, part.part, answer),
+            None => println!("{}Part {}: not solved", indent, part.part),
+        }
+    }
+}
+
+fn print_json(report: &impl Serialize) -> Result<()> {
+    println!("{}", serde_json::to_string_pretty(report)?);
+    Ok(())
+}
+
+fn save(ledger: Option<&Ledger>) -> Result<()> {
+    match ledger {
+        Some(ledger) => ledger.save(&ledger::path()),
+        None => Ok(()),
+    }
+}
+
+fn generate_command(args: &GenerateArgs) -> Result<()> {
+    let generator =
+        days::generator(args.day).ok_or_else(|| anyhow!("day {} has no generator", args.day))?;
+    let seed = args.seed.unwrap_or_else(|| {
+        let seed = rand::random();
+        eprintln!("Seed: {}", seed);
+        seed
+    });
+    print!("{}", generate::seeded(generator, seed, args.size));
+    Ok(())
+}
+
+fn new_day_command(args: &NewDayArgs) -> Result<()> {
+    for path in scaffold::new_day(&args.workspace, args.day)? {
+        println!("Wrote {}", path.display());
+    }
+    println!(
+        "To solve it with `aoc23 run`, add it to runner/Cargo.toml and runner/src/days.rs once \
+         it has a generator"
+    );
+    Ok(())
+}
+
+fn verify_command() -> Result<()> {
+    let ledger = Ledger::load(&ledger::path())?;
+    if ledger.iter().next().is_none() {
+        return Err(anyhow!(
+            "no answers in {}; record some with run --record",
+            ledger::path().display()
+        ));
+    }
+
+    let mut failures = 0;
+    let mut inputs = BTreeMap::new();
+    for (day, part, expected) in ledger.iter() {
+        let input = inputs
+            .entry(day)
+            .or_insert_with(|| input::map(input::default_path(day)));
+        let input = match input {
+            Ok(input) => input,
+            Err(err) => {
+                println!("Day {} part {}: skipped, {:#}", day, part, err);
+                continue;
+            }
+        };
+        let runner = match days::find(day) {
+            Some(runner) => runner,
+            None => {
+                println!("Day {} part {}: FAILED, day is not solved", day, part);
+                failures += 1;
+                continue;
+            }
+        };
+        match runner(input, &[part], &[]).map(|report| report.answer(part).map(str::to_string)) {
+            Ok(Some(answer)) if answer == expected => {
+                println!("Day {} part {}: ok", day, part)
+            }
+            Ok(Some(answer)) => {
+                println!(
+                    "Day {} part {}: CHANGED, recorded {} but got {}",
+                    day, part, expected, answer
+                );
+                failures += 1;
+            }
+            Ok(None) => {
+                println!(
+                    "Day {} part {}: CHANGED, recorded {} but not solved",
+                    day, part, expected
+                );
+                failures += 1;
+            }
+            Err(err) => {
+                println!("Day {} part {}: FAILED, {:#}", day, part, err);
+                failures += 1;
+            }
+        }
+    }
+
+    match failures {
+        0 => Ok(()),
+        n => Err(anyhow!("{} recorded answer(s) did not verify", n)),
+    }
+}
@@ -0,0 +1,181 @@
+//! `new-day`: a new day's crate, made from the templates in `templates/` and added to a
+//! workspace's members. A workspace directory that doesn't exist yet is started as a new
+//! year, like `aoc24/`, sharing this year's common crate.
+
+use anyhow::{anyhow, bail, Context, Result};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+const CARGO_TOML: &str = include_str!("../templates/Cargo.toml.tmpl");
+const LIB_RS: &str = include_str!("../templates/lib.rs.tmpl");
+const MAIN_RS: &str = include_str!("../templates/main.rs.tmpl");
+const BENCH_RS: &str = include_str!("../templates/bench.rs.tmpl");
+
+/// The common crate, which every day depends on.
+const COMMON: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../common");
+
+/// Create `dayN` in `workspace`, returning the files written.
+pub fn new_day(workspace: &Path, day: u8) -> Result<Vec<PathBuf>> {
+    let manifest = workspace.join("Cargo.toml");
+    if !manifest.exists() {
+        fs::create_dir_all(workspace)
+            .with_context(|| format!("creating {}", workspace.display()))?;
+        fs::write(&manifest, "[workspace]\nresolver = \"2\"\nmembers = [\n]\n")
+            .with_context(|| format!("writing {}", manifest.display()))?;
+    }
+    let workspace = workspace
+        .canonicalize()
+        .with_context(|| format!("finding {}", workspace.display()))?;
+
+    let crate_dir = workspace.join(format!("day{}", day));
+    if crate_dir.exists() {
+        bail!("{} already exists", crate_dir.display());
+    }
+    let year = workspace
+        .file_name()
+        .and_then(|name| name.to_str())
+        .filter(|name| name.starts_with("aoc"))
+        .ok_or_else(|| anyhow!("expected a workspace directory named like aoc23"))?;
+    let common = Path::new(COMMON)
+        .canonicalize()
+        .context("finding the common crate")?;
+
+    let fill = |template: &str| {
+        template
+            .replace("{package}", &format!("{}_{}", year, day))
+            .replace("{day}", &day.to_string())
+            .replace("{common}", &relative(&crate_dir, &common).to_string_lossy())
+    };
+    let files = [
+        (crate_dir.join("Cargo.toml"), fill(CARGO_TOML)),
+        (crate_dir.join("src/lib.rs"), fill(LIB_RS)),
+        (crate_dir.join("src/main.rs"), fill(MAIN_RS)),
+        (
+            crate_dir.join(format!("benches/day{}.rs", day)),
+            fill(BENCH_RS),
+        ),
+    ];
+    let mut written = Vec::new();
+    for (path, contents) in files {
+        write(&path, &contents)?;
+        written.push(path);
+    }
+
+    // The example test reads the example input, so start it off empty.
+    let example = workspace.join(format!("examples/day{}.txt", day));
+    if !example.exists() {
+        write(&example, "")?;
+        written.push(example);
+    }
+
+    let contents =
+        fs::read_to_string(&manifest).with_context(|| format!("reading {}", manifest.display()))?;
+    let contents = add_member(&contents, &format!("day{}", day))
+        .with_context(|| format!("updating {}", manifest.display()))?;
+    write(&manifest, &contents)?;
+    written.push(manifest);
+    Ok(written)
+}
+
+fn write(path: &Path, contents: &str) -> Result<()> {
+    let context = || format!("writing {}", path.display());
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).with_context(context)?;
+    }
+    fs::write(path, contents).with_context(context)
+}
+
+/// The path to `to` from the directory `from`, both absolute.
+fn relative(from: &Path, to: &Path) -> PathBuf {
+    let from: Vec<Component> = from.components().collect();
+    let to: Vec<Component> = to.components().collect();
+    let shared = from.iter().zip(&to).take_while(|(a, b)| a == b).count();
+    let mut path = PathBuf::new();
+    for _ in shared..from.len() {
+        path.push("..");
+    }
+    path.extend(&to[shared..]);
+    path
+}
+
+/// `manifest` with `member` added to the workspace's `members`, keeping the days in order after
+/// the other crates.
+fn add_member(manifest: &str, member: &str) -> Result<String> {
+    let start = manifest
+        .find("members = [")
+        .ok_or_else(|| anyhow!("no workspace members list"))?
+        + "members = [".len();
+    let end = start
+        + manifest[start..]
+            .find(']')
+            .ok_or_else(|| anyhow!("unterminated members list"))?;
+
+    let mut members: Vec<&str> = manifest[start..end]
+        .split(',')
+        .map(|m| m.trim().trim_matches('"'))
+        .filter(|m| !m.is_empty())
+        .collect();
+    if members.contains(&member) {
+        bail!("{} is already a member", member);
+    }
+    members.push(member);
+    let day = |m: &str| m.strip_prefix("day").and_then(|n| n.parse::<u8>().ok());
+    members.sort_by_key(|m| (day(m).is_some(), day(m)));
+
+    let list: String = members
+        .iter()
+        .map(|m| format!("    \"{}\",\n", m))
+        .collect();
+    Ok(format!(
+        "{}\n{}{}",
+        &manifest[..start],
+        list,
+        &manifest[end..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn members_stay_in_order() {
+        let manifest = "[workspace]\nmembers = [\n    \"common\",\n    \"runner\",\n    \"day1\",\n    \"day10\",\n]\n";
+        assert_eq!(
+            add_member(manifest, "day2").unwrap(),
+            "[workspace]\nmembers = [\n    \"common\",\n    \"runner\",\n    \"day1\",\n    \"day2\",\n    \"day10\",\n]\n"
+        );
+        assert!(add_member(manifest, "day10").is_err());
+    }
+
+    #[test]
+    fn relative_paths() {
+        assert_eq!(
+            relative(Path::new("/a/aoc24/day1"), Path::new("/a/aoc23/common")),
+            Path::new("../../aoc23/common")
+        );
+        assert_eq!(
+            relative(Path::new("/a/aoc23/day1"), Path::new("/a/aoc23/common")),
+            Path::new("../common")
+        );
+    }
+
+    #[test]
+    fn new_year() {
+        let root = env::temp_dir().join(format!("aoc-new-day-{}", std::process::id()));
+        let workspace = root.join("aoc24");
+        new_day(&workspace, 3).unwrap();
+        let manifest = fs::read_to_string(workspace.join("Cargo.toml")).unwrap();
+        let package = fs::read_to_string(workspace.join("day3/Cargo.toml")).unwrap();
+        let main = fs::read_to_string(workspace.join("day3/src/main.rs")).unwrap();
+        let again = new_day(&workspace, 3);
+        fs::remove_dir_all(&root).unwrap();
+
+        assert!(manifest.contains("members = [\n    \"day3\",\n]"));
+        assert!(package.contains("name = \"aoc24_3\""));
+        assert!(package.contains("path = \"../../../"));
+        assert!(main.contains("use aoc24_3::Day3;"));
+        assert!(again.is_err());
+    }
+}